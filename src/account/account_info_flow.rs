@@ -23,28 +23,23 @@ use crate::{
     AccountId,
     AccountInfoQuery,
     Client,
-    Error,
     Key,
     PublicKey,
     Transaction,
 };
 
-async fn query_pk(client: &Client, account_id: AccountId) -> crate::Result<PublicKey> {
-    let key = AccountInfoQuery::new().account_id(account_id).execute(client).await?.key;
-
-    match key {
-        Key::Single(it) => Ok(it),
-        _ => {
-            Err(Error::signature_verify("`{account_id}`: unsupported key kind: {key:?}".to_owned()))
-        }
-    }
+async fn query_key(client: &Client, account_id: AccountId) -> crate::Result<Key> {
+    Ok(AccountInfoQuery::new().account_id(account_id).execute(client).await?.key)
 }
 
-/// Verify the `signature` for `msg` via the given account's public key.
+/// Verify the `signature` for `msg` via the given account's key.
+///
+/// If the account's key is a key list, the signature has to satisfy it by itself (for instance a 1-of-n threshold key),
+/// use [`verify_signatures`] to verify signatures from multiple keys.
 ///
 /// # Errors
-/// - [`Error::SignatureVerify`] if the signature algorithm doesn't match the account's public key.
-/// - [`Error::SignatureVerify`] if the signature is invalid for the account's public key.
+/// - [`Error::SignatureVerify`](crate::Error::SignatureVerify) if the signature algorithm doesn't match the account's public key.
+/// - [`Error::SignatureVerify`](crate::Error::SignatureVerify) if the signature is invalid for the account's key.
 /// - See [`AccountInfoQuery::execute`]
 pub async fn verify_signature(
     client: &Client,
@@ -52,22 +47,47 @@ pub async fn verify_signature(
     msg: &[u8],
     signature: &[u8],
 ) -> crate::Result<()> {
-    let key = query_pk(client, account_id).await?;
-
-    key.verify(msg, signature)
+    match query_key(client, account_id).await? {
+        Key::Single(key) => key.verify(msg, signature),
+        key => key.satisfaction(&mut |it| it.verify(msg, signature).is_ok()).into_result(),
+    }
 }
 
-/// Returns `Ok(())` if the given account's public key has signed the given transaction.
+/// Verify that `msg` is signed by enough of the given account's key, given `(PublicKey, signature)` pairs.
+///
+/// Works with any account key, including key lists and threshold keys, see [`Key::is_satisfied_by_signatures`].
+///
 /// # Errors
-/// - [`Error::SignatureVerify`] if the private key associated with the account's public key did _not_ sign this transaction,
-///   or the signature associated was invalid.
+/// - [`Error::SignatureVerify`](crate::Error::SignatureVerify) if the account's key is not satisfied by `signatures`.
 /// - See [`AccountInfoQuery::execute`]
+pub async fn verify_signatures<S: AsRef<[u8]>>(
+    client: &Client,
+    account_id: AccountId,
+    msg: &[u8],
+    signatures: &[(PublicKey, S)],
+) -> crate::Result<()> {
+    query_key(client, account_id).await?.verify_signatures(msg, signatures)
+}
+
+/// Returns `Ok(())` if the given account's key has signed the given transaction.
+///
+/// Works with any account key, including key lists and threshold keys, see [`Key::is_satisfied_by`].
+/// # Errors
+/// - [`Error::SignatureVerify`](crate::Error::SignatureVerify) if the account's key is not satisfied by this transaction's signatures,
+///   or a signature associated was invalid.
+/// - See [`Transaction::freeze`] and [`AccountInfoQuery::execute`]
 pub async fn verify_transaction_signature<D: TransactionExecute>(
     client: &Client,
     account_id: AccountId,
     transaction: &mut Transaction<D>,
 ) -> crate::Result<()> {
-    let key = query_pk(client, account_id).await?;
+    let key = match query_key(client, account_id).await? {
+        // checked without signing, so that asynchronous signers keep working for single keys.
+        Key::Single(key) => return key.verify_transaction(transaction),
+        key => key,
+    };
+
+    transaction.freeze()?;
 
-    key.verify_transaction(transaction)
+    key.is_satisfied_by(transaction)?.into_result()
 }
@@ -0,0 +1,217 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::VecDeque;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// Configuration for the per-node circuit breaker of a [`Client`](crate::Client).
+///
+/// Each node's breaker watches the outcome of the most recent requests made to that node.
+/// When too many of them fail the breaker *opens*, and the node is avoided for [`open_duration`](Self::open_duration).
+/// After that the breaker is *half-open*, the node is probed (with a ping, or the next request explicitly sent to it)
+/// and the breaker closes again if the probe succeeds, otherwise it re-opens.
+///
+/// Only failures of the node itself count, such as gRPC errors, timeouts and `BUSY` responses,
+/// a request that's rejected because of something wrong with the request doesn't.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// The fraction of failed requests in the window (`0.0..=1.0`) at which the breaker opens.
+    pub failure_ratio: f64,
+
+    /// The minimum number of requests in the window before the breaker can open.
+    pub minimum_requests: usize,
+
+    /// The number of most recent requests that the failure ratio is calculated over.
+    pub window_size: usize,
+
+    /// How long the breaker stays open before it becomes half-open.
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_ratio: 0.5,
+            minimum_requests: 5,
+            window_size: 20,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The state of a node's circuit breaker.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// The node is used as normal.
+    #[default]
+    Closed,
+
+    /// The node failed too often and is being avoided.
+    Open,
+
+    /// The node was avoided for long enough, and it'll be probed before it's used again.
+    HalfOpen,
+}
+
+#[derive(Default)]
+enum State {
+    #[default]
+    Closed,
+    // half-open once `until` has passed.
+    Open {
+        until: Instant,
+    },
+}
+
+/// The circuit breaker for a single node.
+#[derive(Default)]
+pub(crate) struct CircuitBreaker {
+    state: State,
+    // `true` for each failure.
+    window: VecDeque<bool>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn state(&self, now: Instant) -> CircuitState {
+        match self.state {
+            State::Closed => CircuitState::Closed,
+            State::Open { until } if now < until => CircuitState::Open,
+            State::Open { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    pub(crate) fn record(&mut self, config: &CircuitBreakerConfig, failed: bool, now: Instant) {
+        match self.state(now) {
+            CircuitState::Closed => {
+                self.window.push_back(failed);
+
+                while self.window.len() > config.window_size.max(1) {
+                    self.window.pop_front();
+                }
+
+                let failures = self.window.iter().filter(|it| **it).count();
+
+                #[allow(clippy::cast_precision_loss)]
+                let ratio = failures as f64 / self.window.len() as f64;
+
+                if self.window.len() >= config.minimum_requests && ratio >= config.failure_ratio {
+                    self.open(config, now);
+                }
+            }
+
+            // the probe decides what happens next.
+            CircuitState::HalfOpen => {
+                if failed {
+                    self.open(config, now);
+                } else {
+                    self.state = State::Closed;
+                    self.window.clear();
+                }
+            }
+
+            // requests that were already in flight when the breaker opened don't change anything.
+            CircuitState::Open => {}
+        }
+    }
+
+    fn open(&mut self, config: &CircuitBreakerConfig, now: Instant) {
+        log::debug!("Opening circuit breaker for {:?}", config.open_duration);
+
+        self.state = State::Open { until: now + config.open_duration };
+        self.window.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use super::{
+        CircuitBreaker,
+        CircuitBreakerConfig,
+        CircuitState,
+    };
+
+    const CONFIG: CircuitBreakerConfig = CircuitBreakerConfig {
+        failure_ratio: 0.5,
+        minimum_requests: 4,
+        window_size: 6,
+        open_duration: Duration::from_secs(10),
+    };
+
+    #[test]
+    fn opens_at_failure_ratio() {
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        for failed in [false, false, true] {
+            breaker.record(&CONFIG, failed, now);
+        }
+
+        // not enough requests yet.
+        assert_eq!(breaker.state(now), CircuitState::Closed);
+
+        breaker.record(&CONFIG, true, now);
+
+        assert_eq!(breaker.state(now), CircuitState::Open);
+    }
+
+    #[test]
+    fn window_forgets_old_failures() {
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        for failed in [true, false, false, false, false, false, false, true, true] {
+            breaker.record(&CONFIG, failed, now);
+        }
+
+        // the window is `[false, false, false, false, true, true]`.
+        assert_eq!(breaker.state(now), CircuitState::Closed);
+    }
+
+    #[test]
+    fn half_open_probe() {
+        let now = Instant::now();
+        let later = now + CONFIG.open_duration;
+        let mut breaker = CircuitBreaker::default();
+
+        for _ in 0..4 {
+            breaker.record(&CONFIG, true, now);
+        }
+
+        assert_eq!(breaker.state(later - Duration::from_millis(1)), CircuitState::Open);
+        assert_eq!(breaker.state(later), CircuitState::HalfOpen);
+
+        breaker.record(&CONFIG, true, later);
+        assert_eq!(breaker.state(later), CircuitState::Open);
+
+        let later = later + CONFIG.open_duration;
+        assert_eq!(breaker.state(later), CircuitState::HalfOpen);
+
+        breaker.record(&CONFIG, false, later);
+        assert_eq!(breaker.state(later), CircuitState::Closed);
+    }
+}
@@ -19,11 +19,14 @@
  */
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    Error,
+    Keystore,
     PrivateKey,
 };
 
@@ -47,12 +50,65 @@ where
 #[derive(serde_derive::Deserialize)]
 pub(super) struct Operator {
     account_id: FromStrProxy<AccountId>,
-    private_key: FromStrProxy<PrivateKey>,
+    private_key: Option<FromStrProxy<PrivateKey>>,
+    keystore: Option<OperatorKeystore>,
 }
 
-impl From<Operator> for super::Operator {
-    fn from(value: Operator) -> Self {
-        Self { account_id: value.account_id.0, signer: AnySigner::PrivateKey(value.private_key.0) }
+impl Operator {
+    /// Resolves the operator's private key, which is either inline, or in a keystore file.
+    pub(super) fn resolve(
+        self,
+        keystore_password: Option<&[u8]>,
+    ) -> crate::Result<super::Operator> {
+        let account_id = self.account_id.0;
+
+        let private_key = match (self.private_key, self.keystore) {
+            (Some(private_key), None) => private_key.0,
+            (None, Some(keystore)) => keystore.private_key(account_id, keystore_password)?,
+            _ => {
+                return Err(Error::basic_parse(
+                    "operator must have exactly one of `private_key` or `keystore`",
+                ))
+            }
+        };
+
+        Ok(super::Operator { account_id, signer: AnySigner::PrivateKey(private_key) })
+    }
+}
+
+/// The operator's key in a [`Keystore`] file.
+#[derive(serde_derive::Deserialize)]
+struct OperatorKeystore {
+    path: PathBuf,
+    label: Option<String>,
+}
+
+impl OperatorKeystore {
+    /// Returns the key with `label`, or, without a label, the key for `account_id` (or the only key).
+    fn private_key(
+        self,
+        account_id: AccountId,
+        password: Option<&[u8]>,
+    ) -> crate::Result<PrivateKey> {
+        let password = password.ok_or_else(|| {
+            Error::keystore(
+                "the operator's key is in a keystore, but no password was given for it, see `Client::from_config_with_keystore_password`",
+            )
+        })?;
+
+        let keystore = Keystore::load(&self.path, password)?;
+
+        let entry = match &self.label {
+            Some(label) => keystore.get(label),
+            None => keystore.get_by_account_id(account_id).or(match keystore.entries() {
+                [entry] => Some(entry),
+                _ => None,
+            }),
+        };
+
+        entry.map(|it| it.private_key.clone()).ok_or_else(|| {
+            Error::keystore(format!("no operator key found in `{}`", self.path.display()))
+        })
     }
 }
 
@@ -82,7 +138,7 @@ pub(super) struct ClientConfigInner {
 impl From<ClientConfigInner> for ClientConfig {
     fn from(value: ClientConfigInner) -> Self {
         Self {
-            operator: value.operator.map(Into::into),
+            operator: value.operator,
             network: match value.network {
                 Either::Left(it) => Either::Left(it.into_iter().map(|(k, v)| (k, v.0)).collect()),
                 Either::Right(it) => Either::Right(it),
@@ -93,7 +149,114 @@ impl From<ClientConfigInner> for ClientConfig {
 }
 
 pub(super) struct ClientConfig {
-    pub(super) operator: Option<super::Operator>,
+    pub(super) operator: Option<Operator>,
     pub(super) network: Either<HashMap<String, AccountId>, NetworkName>,
     pub(super) mirror_network: Option<Either<Vec<String>, NetworkName>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::{
+        Path,
+        PathBuf,
+    };
+
+    use assert_matches::assert_matches;
+
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        Keystore,
+        KeystoreEntry,
+        PrivateKey,
+    };
+
+    /// A keystore file in the temp dir, which is deleted on drop.
+    struct TempKeystore(PathBuf);
+
+    impl TempKeystore {
+        fn save(keystore: &Keystore, password: &str) -> Self {
+            let name = format!("hedera-sdk-test-{}.json", hex::encode(rand::random::<[u8; 8]>()));
+            let path = std::env::temp_dir().join(name);
+
+            keystore.save(&path, password).unwrap();
+
+            Self(path)
+        }
+    }
+
+    impl Drop for TempKeystore {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn config(path: &Path, label: &str) -> String {
+        serde_json::json!({
+            "network": { "127.0.0.1:50211": "0.0.3" },
+            "operator": {
+                "account_id": "0.0.1001",
+                "keystore": { "path": path, "label": label },
+            },
+        })
+        .to_string()
+    }
+
+    fn operator_keystore() -> (PrivateKey, TempKeystore) {
+        let private_key = PrivateKey::generate_ed25519();
+
+        let mut operator = KeystoreEntry::new(private_key.clone());
+        operator.label = Some("operator".to_owned());
+
+        let mut keystore = Keystore::new();
+        keystore
+            .set_light_kdf(true)
+            .add(KeystoreEntry::new(PrivateKey::generate_ecdsa()))
+            .add(operator);
+
+        (private_key, TempKeystore::save(&keystore, "hunter2"))
+    }
+
+    #[test]
+    fn operator_from_keystore() {
+        let (private_key, keystore) = operator_keystore();
+
+        let client =
+            Client::from_config_with_keystore_password(&config(&keystore.0, "operator"), "hunter2")
+                .unwrap();
+
+        assert_eq!(client.get_operator_account_id(), Some(AccountId::new(0, 0, 1001)));
+        assert_eq!(client.get_operator_public_key(), Some(private_key.public_key()));
+    }
+
+    #[test]
+    fn operator_from_keystore_wrong_password() {
+        let (_, keystore) = operator_keystore();
+
+        let res =
+            Client::from_config_with_keystore_password(&config(&keystore.0, "operator"), "hunter3");
+
+        assert_matches!(res, Err(Error::Keystore(_)));
+    }
+
+    #[test]
+    fn operator_from_keystore_missing_label() {
+        let (_, keystore) = operator_keystore();
+
+        let res =
+            Client::from_config_with_keystore_password(&config(&keystore.0, "treasury"), "hunter2");
+
+        assert_matches!(res, Err(Error::Keystore(e)) if e.to_string().contains("no operator key"));
+    }
+
+    #[test]
+    fn operator_from_keystore_without_password() {
+        let (_, keystore) = operator_keystore();
+
+        assert_matches!(
+            Client::from_config(&config(&keystore.0, "operator")),
+            Err(Error::Keystore(_))
+        );
+    }
+}
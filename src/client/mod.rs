@@ -40,22 +40,38 @@ pub(crate) use operator::Operator;
 use parking_lot::RwLock;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use self::network::managed::ManagedNetwork;
 use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
+use crate::interceptor::Interceptors;
+use crate::network_stats::RequestMetrics;
 use crate::ping_query::PingQuery;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    ArcSwap,
     ArcSwapOption,
+    CircuitBreakerConfig,
+    CircuitState,
+    DefaultRetryPolicy,
     Error,
     Hbar,
+    Interceptor,
     LedgerId,
+    NetworkStats,
     NodeAddressBook,
     NodeAddressBookQuery,
+    NodeSelector,
     PrivateKey,
     PublicKey,
+    RandomNodeSelector,
+    RetryPolicy,
+    Signer,
 };
 
 #[cfg(feature = "serde")]
@@ -95,6 +111,7 @@ struct ClientBuilder {
     ledger_id: Option<LedgerId>,
     auto_validate_checksums: bool,
     regenerate_transaction_ids: bool,
+    transport_security: bool,
     update_network: bool,
     backoff: ClientBackoff,
 }
@@ -110,6 +127,7 @@ impl ClientBuilder {
             ledger_id: None,
             auto_validate_checksums: false,
             regenerate_transaction_ids: true,
+            transport_security: false,
             update_network: true,
             backoff: ClientBackoff::default(),
         }
@@ -132,6 +150,7 @@ impl ClientBuilder {
             ledger_id,
             auto_validate_checksums,
             regenerate_transaction_ids,
+            transport_security,
             update_network,
             backoff,
         } = self;
@@ -155,6 +174,16 @@ impl ClientBuilder {
             ledger_id: ArcSwapOption::new(ledger_id.map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
+            transport_security: AtomicBool::new(transport_security),
+            interceptors: ArcSwap::new(Arc::new(Vec::new())),
+            request_metrics: Arc::default(),
+            node_selector: RwLock::new(
+                Arc::new(RandomNodeSelector).unsize(Coercion!(to dyn NodeSelector)),
+            ),
+            circuit_breaker: RwLock::new(None),
+            retry_policy: RwLock::new(
+                Arc::new(DefaultRetryPolicy).unsize(Coercion!(to dyn RetryPolicy)),
+            ),
             network_update_tx,
             backoff: RwLock::new(backoff),
         }))
@@ -169,6 +198,12 @@ struct ClientInner {
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
+    transport_security: AtomicBool,
+    interceptors: ArcSwap<Vec<Arc<dyn Interceptor>>>,
+    request_metrics: Arc<RequestMetrics>,
+    node_selector: RwLock<Arc<dyn NodeSelector>>,
+    circuit_breaker: RwLock<Option<CircuitBreakerConfig>>,
+    retry_policy: RwLock<Arc<dyn RetryPolicy>>,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
 }
@@ -186,9 +221,14 @@ impl fmt::Debug for Client {
 
 impl Client {
     #[cfg(feature = "serde")]
-    fn from_config_data(config: config::ClientConfig) -> crate::Result<Self> {
+    fn from_config_data(
+        config: config::ClientConfig,
+        keystore_password: Option<&[u8]>,
+    ) -> crate::Result<Self> {
         let config::ClientConfig { operator, network, mirror_network } = config;
 
+        let operator = operator.map(|it| it.resolve(keystore_password)).transpose()?;
+
         // fixme: check to ensure net and mirror net are the same when they're a network name (no other SDK actually checks this though)
         let client = match network {
             config::Either::Left(network) => Client::for_network(network)?,
@@ -223,15 +263,41 @@ impl Client {
 
     /// Create a client from the given json config.
     ///
+    /// The operator's key is either an inline `private_key`,
+    /// or a `keystore` (`{ "path": "...", "label": "..." }`), which requires
+    /// [`from_config_with_keystore_password`](Self::from_config_with_keystore_password).
+    ///
     /// # Errors
     /// - [`Error::BasicParse`] if an error occurs parsing the configuration.
+    /// - [`Error::Keystore`] if the operator's key is in a keystore.
     #[cfg(feature = "serde")]
     pub fn from_config(json: &str) -> crate::Result<Self> {
         let config = serde_json::from_str::<config::ClientConfigInner>(json)
             .map_err(crate::Error::basic_parse)?
             .into();
 
-        Self::from_config_data(config)
+        Self::from_config_data(config, None)
+    }
+
+    /// Create a client from the given json config,
+    /// where the operator's key can be in a [`Keystore`](crate::Keystore) encrypted with `keystore_password`.
+    ///
+    /// The keystore entry is chosen by `label` if the config has one,
+    /// otherwise by the operator's account ID, or if the keystore only has one key, that key.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an error occurs parsing the configuration.
+    /// - [`Error::Keystore`] if the keystore can't be loaded, or doesn't contain the operator's key.
+    #[cfg(feature = "serde")]
+    pub fn from_config_with_keystore_password(
+        json: &str,
+        keystore_password: impl AsRef<[u8]>,
+    ) -> crate::Result<Self> {
+        let config = serde_json::from_str::<config::ClientConfigInner>(json)
+            .map_err(crate::Error::basic_parse)?
+            .into();
+
+        Self::from_config_data(config, Some(keystore_password.as_ref()))
     }
 
     /// Returns the addresses for the configured mirror network.
@@ -290,6 +356,25 @@ impl Client {
         Ok(ClientBuilder::new(network).disable_network_updating().build())
     }
 
+    /// Construct a client that talks to the given [`MockNetwork`](crate::MockNetwork).
+    ///
+    /// Note that this disables network auto-updating.
+    #[cfg(feature = "mock")]
+    #[must_use]
+    pub fn for_mock(mock: &crate::MockNetwork) -> Self {
+        let client = Self::for_network(mock.network())
+            .expect("the mock network's addresses should always be valid");
+
+        client.mirrornet().store(
+            MirrorNetworkData::plaintext(
+                mock.mirror_network().into_iter().map(Cow::Owned).collect(),
+            )
+            .into(),
+        );
+
+        client
+    }
+
     /// Construct a client from a select mirror network
     pub async fn for_mirror_network(mirror_networks: Vec<String>) -> crate::Result<Self> {
         let network_addresses: HashMap<String, AccountId> = HashMap::new();
@@ -449,6 +534,114 @@ impl Client {
         self.0.regenerate_transaction_ids.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if connections to consensus nodes use TLS.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn transport_security(&self) -> bool {
+        self.0.transport_security.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable TLS for connections to consensus nodes.
+    ///
+    /// When enabled, nodes are connected to on port `50212` rather than `50211`,
+    /// and a node's certificate is only accepted if its hash matches the
+    /// [`tls_certificate_hash`](crate::NodeAddress::tls_certificate_hash) published for that node in the address book.
+    ///
+    /// Note: Nodes without a known certificate hash (for instance, nodes given to [`for_network`](Self::for_network),
+    /// or any node before the network has been updated from the address book) can't be connected to over TLS.
+    pub fn set_transport_security(&self, value: bool) {
+        self.0.transport_security.store(value, Ordering::Relaxed);
+    }
+
+    /// Adds an [`Interceptor`] that's called around every attempt this client makes at executing a request.
+    ///
+    /// Interceptors are called in the order they were added.
+    pub fn add_interceptor<I: Interceptor + 'static>(&self, interceptor: I) {
+        let interceptor: Arc<dyn Interceptor> =
+            Arc::new(interceptor).unsize(Coercion!(to dyn Interceptor));
+
+        self.0.interceptors.rcu(|old| {
+            let mut interceptors = Vec::clone(old);
+            interceptors.push(Arc::clone(&interceptor));
+            Arc::new(interceptors)
+        });
+    }
+
+    /// Removes every [`Interceptor`] from this client.
+    pub fn clear_interceptors(&self) {
+        self.0.interceptors.store(Arc::new(Vec::new()));
+    }
+
+    pub(crate) fn interceptors(&self) -> Interceptors {
+        self.0.interceptors.load_full()
+    }
+
+    /// Returns a snapshot of the health and performance of the network as seen by this client.
+    ///
+    /// Node stats are kept for as long as the node is in the network, even when the network is updated,
+    /// request stats are kept for the lifetime of the client.
+    #[must_use]
+    pub fn network_stats(&self) -> NetworkStats {
+        NetworkStats {
+            nodes: self.net().0.load().node_stats(),
+            requests: self.0.request_metrics.snapshot(),
+        }
+    }
+
+    pub(crate) fn request_metrics(&self) -> Arc<RequestMetrics> {
+        Arc::clone(&self.0.request_metrics)
+    }
+
+    /// Sets the [`NodeSelector`] that chooses which nodes are tried when executing requests,
+    /// and which nodes are used when freezing a transaction without explicit node account IDs.
+    ///
+    /// The default is [`RandomNodeSelector`].
+    pub fn set_node_selector<S: NodeSelector + 'static>(&self, selector: S) {
+        *self.0.node_selector.write() = Arc::new(selector).unsize(Coercion!(to dyn NodeSelector));
+    }
+
+    pub(crate) fn node_selector(&self) -> Arc<dyn NodeSelector> {
+        Arc::clone(&self.0.node_selector.read())
+    }
+
+    /// Sets the [`RetryPolicy`] that decides what happens after an attempt at executing a request fails.
+    ///
+    /// This can be overridden per request, with [`Transaction::retry_policy`](crate::Transaction::retry_policy)
+    /// and [`Query::retry_policy`](crate::Query::retry_policy).
+    ///
+    /// The default is [`DefaultRetryPolicy`].
+    pub fn set_retry_policy<P: RetryPolicy + 'static>(&self, policy: P) {
+        *self.0.retry_policy.write() = Arc::new(policy).unsize(Coercion!(to dyn RetryPolicy));
+    }
+
+    pub(crate) fn retry_policy(&self) -> Arc<dyn RetryPolicy> {
+        Arc::clone(&self.0.retry_policy.read())
+    }
+
+    /// Returns the configuration of the per-node circuit breakers, or `None` if they're disabled.
+    ///
+    /// Circuit breakers are disabled by default.
+    #[must_use]
+    pub fn circuit_breaker(&self) -> Option<CircuitBreakerConfig> {
+        *self.0.circuit_breaker.read()
+    }
+
+    /// Enables per-node circuit breakers with the given configuration, or disables them with `None`.
+    ///
+    /// While a node's circuit breaker is open the node is avoided, see [`CircuitBreakerConfig`] for details.
+    pub fn set_circuit_breaker(&self, config: Option<CircuitBreakerConfig>) {
+        *self.0.circuit_breaker.write() = config;
+    }
+
+    /// Returns the state of each node's circuit breaker.
+    ///
+    /// Nodes with an [`Open`](CircuitState::Open) circuit are currently being avoided.
+    #[must_use]
+    pub fn circuit_states(&self) -> HashMap<AccountId, CircuitState> {
+        self.net().0.load().circuit_states()
+    }
+
     /// Sets the account that will, by default, be paying for transactions and queries built with
     /// this client.
     ///
@@ -481,6 +674,20 @@ impl Client {
         })));
     }
 
+    /// Sets the account that will, by default, be paying for transactions and queries built with
+    /// this client.
+    ///
+    /// The operator account ID is used to generate the default transaction ID for all transactions
+    /// executed with this client.
+    ///
+    /// The operator `signer` is used to sign all transactions executed by this client,
+    /// this allows using keys that aren't held in memory, such as keys kept in an HSM.
+    pub fn set_operator_with_signer<S: Signer + 'static>(&self, id: AccountId, signer: S) {
+        self.0
+            .operator
+            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::signer(signer) })));
+    }
+
     /// Gets a reference to the configured network.
     pub(crate) fn net(&self) -> &Network {
         &self.0.network.primary
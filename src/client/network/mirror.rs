@@ -79,11 +79,26 @@ pub(crate) struct MirrorNetworkData {
     addresses: Vec<Cow<'static, str>>,
     channel: OnceCell<Channel>,
     tls_config: ClientTlsConfig,
+    /// Connect without TLS, only used for the mock network.
+    #[cfg(feature = "mock")]
+    plaintext: bool,
 }
 
 impl MirrorNetworkData {
     pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
-        Self { addresses, channel: OnceCell::new(), tls_config: ClientTlsConfig::new() }
+        Self {
+            addresses,
+            channel: OnceCell::new(),
+            tls_config: ClientTlsConfig::new(),
+            #[cfg(feature = "mock")]
+            plaintext: false,
+        }
+    }
+
+    /// Mirror nodes that don't do TLS, such as the one of a [`MockNetwork`](crate::MockNetwork).
+    #[cfg(feature = "mock")]
+    pub(crate) fn plaintext(addresses: Vec<Cow<'static, str>>) -> Self {
+        Self { plaintext: true, ..Self::from_addresses(addresses) }
     }
 
     pub(crate) fn from_static(network: &[&'static str], tls_config: ClientTlsConfig) -> Self {
@@ -93,13 +108,29 @@ impl MirrorNetworkData {
             addresses.push(Cow::Borrowed(*address));
         }
 
-        Self { addresses, channel: OnceCell::new(), tls_config }
+        Self {
+            addresses,
+            channel: OnceCell::new(),
+            tls_config,
+            #[cfg(feature = "mock")]
+            plaintext: false,
+        }
     }
 
     pub(crate) fn channel(&self) -> Channel {
         self.channel
             .get_or_init(|| {
                 let endpoints = self.addresses.iter().map(|address| {
+                    #[cfg(feature = "mock")]
+                    if self.plaintext {
+                        return Endpoint::from_shared(format!("http://{address}"))
+                            .unwrap()
+                            .keep_alive_timeout(Duration::from_secs(10))
+                            .keep_alive_while_idle(true)
+                            .tcp_keepalive(Some(Duration::from_secs(10)))
+                            .connect_timeout(Duration::from_secs(10));
+                    }
+
                     let uri = format!("https://{address}");
                     Endpoint::from_shared(uri)
                         .unwrap()
@@ -20,6 +20,7 @@
 
 pub(super) mod managed;
 pub(super) mod mirror;
+mod tls;
 
 use std::borrow::Cow;
 use std::collections::{
@@ -27,7 +28,10 @@ use std::collections::{
     HashMap,
 };
 use std::fmt;
-use std::net::Ipv4Addr;
+use std::net::{
+    Ipv4Addr,
+    SocketAddrV4,
+};
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::time::{
@@ -37,14 +41,30 @@ use std::time::{
 
 use backoff::backoff::Backoff;
 use once_cell::sync::OnceCell;
-use parking_lot::RwLock;
-use rand::thread_rng;
+use parking_lot::{
+    Mutex,
+    RwLock,
+};
 use tonic::transport::{
     Channel,
     Endpoint,
 };
 use triomphe::Arc;
 
+use crate::circuit_breaker::{
+    CircuitBreaker,
+    CircuitBreakerConfig,
+    CircuitState,
+};
+use crate::network_stats::{
+    NodeBackoffStats,
+    NodeMetrics,
+    NodeStats,
+};
+use crate::node_selector::{
+    NodeCandidate,
+    NodeSelector,
+};
 use crate::{
     AccountId,
     ArcSwap,
@@ -180,7 +200,7 @@ pub(crate) struct NetworkData {
     node_ids: Box<[AccountId]>,
     backoff: RwLock<NodeBackoff>,
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
-    health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
+    state: Box<[Arc<NodeState>]>,
     connections: Box<[NodeConnection]>,
 }
 
@@ -193,21 +213,21 @@ impl NetworkData {
         let mut map = HashMap::with_capacity(network.len());
         let mut node_ids = Vec::with_capacity(network.len());
         let mut connections = Vec::with_capacity(network.len());
-        let mut health = Vec::with_capacity(network.len());
+        let mut state = Vec::with_capacity(network.len());
 
         for (i, (num, address)) in network.iter().copied().enumerate() {
             let node_account_id = AccountId::from(num);
 
             map.insert(node_account_id, i);
             node_ids.push(node_account_id);
-            health.push(Arc::default());
+            state.push(Arc::default());
             connections.push(NodeConnection::new_static(address));
         }
 
         Self {
             map,
             node_ids: node_ids.into_boxed_slice(),
-            health: health.into_boxed_slice(),
+            state: state.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
         }
@@ -219,7 +239,7 @@ impl NetworkData {
         let mut map = HashMap::with_capacity(address_book.len());
         let mut node_ids = Vec::with_capacity(address_book.len());
         let mut connections = Vec::with_capacity(address_book.len());
-        let mut health = Vec::with_capacity(address_book.len());
+        let mut state = Vec::with_capacity(address_book.len());
 
         for (i, address) in address_book.iter().enumerate() {
             let new: BTreeSet<_> = address
@@ -229,36 +249,47 @@ impl NetworkData {
                 .map(|it| (*it.ip()).into())
                 .collect();
 
+            let new_tls: BTreeSet<_> = address
+                .service_endpoints
+                .iter()
+                .filter(|it| it.port() == NodeConnection::TLS_PORT)
+                .map(|it| HostAndPort::from(*it))
+                .collect();
+
+            let tls_certificate_hash =
+                Some(address.tls_certificate_hash.clone()).filter(|it| !it.is_empty());
+
             // if the node is the exact same we want to reuse everything (namely the connections and `healthy`).
             // if the node has different routes then we still want to reuse `healthy` but replace the channel with a new channel.
             // if the node just flat out doesn't exist in `old`, we want to add the new node.
             // and, last but not least, if the node doesn't exist in `new` we want to get rid of it.
             let upsert = match old.map.get(&address.node_account_id) {
                 Some(&account) => {
-                    let connection =
-                        match old.connections[account].addresses.symmetric_difference(&new).count()
-                        {
-                            0 => old.connections[account].clone(),
-                            _ => NodeConnection { addresses: new, channel: OnceCell::new() },
-                        };
-
-                    (old.health[account].clone(), connection)
-                }
-                None => {
-                    (Arc::default(), NodeConnection { addresses: new, channel: OnceCell::new() })
+                    let old_connection = &old.connections[account];
+                    let unchanged = old_connection.addresses == new
+                        && old_connection.tls_addresses == new_tls
+                        && old_connection.tls_certificate_hash == tls_certificate_hash;
+
+                    let connection = match unchanged {
+                        true => old_connection.clone(),
+                        false => NodeConnection::new(new, new_tls, tls_certificate_hash),
+                    };
+
+                    (old.state[account].clone(), connection)
                 }
+                None => (Arc::default(), NodeConnection::new(new, new_tls, tls_certificate_hash)),
             };
 
             map.insert(address.node_account_id, i);
             node_ids.push(address.node_account_id);
-            health.push(upsert.0);
+            state.push(upsert.0);
             connections.push(upsert.1);
         }
 
         Self {
             map,
             node_ids: node_ids.into_boxed_slice(),
-            health: health.into_boxed_slice(),
+            state: state.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
         }
@@ -269,7 +300,7 @@ impl NetworkData {
         let mut map: HashMap<AccountId, usize> = HashMap::new();
         let mut node_ids = Vec::new();
         let mut connections: Vec<NodeConnection> = Vec::new();
-        let mut health = Vec::new();
+        let mut state = Vec::new();
 
         for (address, node) in addresses {
             let next_index = node_ids.len();
@@ -284,13 +315,14 @@ impl NetworkData {
                     entry.insert(next_index);
                     node_ids.push(*node);
                     // fixme: keep the channel around more.
-                    connections.push(NodeConnection {
-                        addresses: BTreeSet::from([address]),
-                        channel: OnceCell::new(),
-                    });
-
-                    health.push(match self.map.get(node) {
-                        Some(it) => self.health[*it].clone(),
+                    connections.push(NodeConnection::new(
+                        BTreeSet::from([address]),
+                        BTreeSet::new(),
+                        None,
+                    ));
+
+                    state.push(match self.map.get(node) {
+                        Some(it) => self.state[*it].clone(),
                         None => Arc::default(),
                     });
                 }
@@ -300,7 +332,7 @@ impl NetworkData {
         Ok(Self {
             map,
             node_ids: node_ids.into_boxed_slice(),
-            health: health.into_boxed_slice(),
+            state: state.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
         })
@@ -359,52 +391,182 @@ impl NetworkData {
     pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
         let now = Instant::now();
 
-        self.health[node_index].write().mark_unhealthy(*self.backoff.read(), now);
+        self.state[node_index].health.write().mark_unhealthy(*self.backoff.read(), now);
     }
 
     pub(crate) fn mark_node_healthy(&self, node_index: usize) {
-        self.health[node_index].write().mark_healthy(Instant::now());
+        self.state[node_index].health.write().mark_healthy(Instant::now());
     }
 
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: Instant) -> bool {
         // a healthy node has a healthiness before now.
 
-        self.health[node_index].read().is_healthy(now)
+        self.state[node_index].health.read().is_healthy(now)
     }
 
     pub(crate) fn node_recently_pinged(&self, node_index: usize, now: Instant) -> bool {
-        self.health[node_index].read().recently_pinged(now)
+        self.state[node_index].health.read().recently_pinged(now)
+    }
+
+    pub(crate) fn circuit_state(&self, node_index: usize, now: Instant) -> CircuitState {
+        self.state[node_index].circuit_breaker.lock().state(now)
+    }
+
+    pub(crate) fn record_circuit_breaker(
+        &self,
+        node_index: usize,
+        config: &CircuitBreakerConfig,
+        failed: bool,
+    ) {
+        self.state[node_index].circuit_breaker.lock().record(config, failed, Instant::now());
+    }
+
+    pub(crate) fn circuit_states(&self) -> HashMap<AccountId, CircuitState> {
+        let now = Instant::now();
+
+        (0..self.node_ids.len())
+            .map(|it| (self.node_ids[it], self.circuit_state(it, now)))
+            .collect()
     }
 
-    pub(crate) fn healthy_node_indexes(&self, time: Instant) -> impl Iterator<Item = usize> + '_ {
-        (0..self.node_ids.len()).filter(move |index| self.is_node_healthy(*index, time))
+    pub(crate) fn record_node_latency(&self, node_index: usize, latency: Duration) {
+        self.state[node_index].metrics.lock().record_latency(latency);
     }
 
-    pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
-        self.healthy_node_indexes(Instant::now()).map(|it| self.node_ids[it])
+    pub(crate) fn record_node_success(&self, node_index: usize) {
+        self.state[node_index].metrics.lock().record_success();
+    }
+
+    pub(crate) fn record_node_failure(&self, node_index: usize, error: &Error) {
+        self.state[node_index].metrics.lock().record_failure(error);
+    }
+
+    pub(crate) fn node_stats(&self) -> HashMap<AccountId, NodeStats> {
+        let now = Instant::now();
+
+        self.node_ids
+            .iter()
+            .zip(self.state.iter())
+            .map(|(id, state)| {
+                let backoff = state.health.read().backoff_stats(now);
+                let circuit = state.circuit_breaker.lock().state(now);
+                (*id, state.metrics.lock().snapshot(backoff, circuit))
+            })
+            .collect()
+    }
+
+    fn node_candidate(&self, node_index: usize) -> NodeCandidate {
+        NodeCandidate {
+            node_account_id: self.node_ids[node_index],
+            mean_latency: self.state[node_index].metrics.lock().mean_latency(),
+        }
+    }
+
+    /// Asks `selector` to pick from `indexes`, mapping its choice back to node indexes.
+    fn select_from(
+        &self,
+        selector: &dyn NodeSelector,
+        indexes: &[usize],
+        explicit: bool,
+    ) -> Vec<usize> {
+        let candidates: Vec<_> = indexes.iter().map(|&it| self.node_candidate(it)).collect();
+
+        let mut selected = Vec::with_capacity(indexes.len());
+
+        for index in selector.select(&candidates, explicit) {
+            if let Some(&index) = indexes.get(index) {
+                if !selected.contains(&index) {
+                    selected.push(index);
+                }
+            }
+        }
+
+        selected
+    }
+
+    /// Returns true if the node is healthy and, when `avoid_open_circuits` is set, its circuit breaker isn't open.
+    fn is_node_usable(&self, node_index: usize, now: Instant, avoid_open_circuits: bool) -> bool {
+        self.is_node_healthy(node_index, now)
+            && !(avoid_open_circuits && self.circuit_state(node_index, now) == CircuitState::Open)
+    }
+
+    /// Returns the indexes of the nodes to try, in order, or `None` if there aren't any to try right now.
+    ///
+    /// Explicit nodes are filtered down to the usable ones (unless none of them are usable).
+    pub(crate) fn select_node_indexes(
+        &self,
+        selector: &dyn NodeSelector,
+        explicit_node_indexes: Option<&[usize]>,
+        avoid_open_circuits: bool,
+    ) -> Option<Vec<usize>> {
+        // using the same reference time avoids situations where a node that wasn't available becomes available.
+        let now = Instant::now();
+
+        let selected = match explicit_node_indexes {
+            Some(indexes) => {
+                let tmp: Vec<_> = indexes
+                    .iter()
+                    .copied()
+                    .filter(|it| self.is_node_usable(*it, now, avoid_open_circuits))
+                    .collect();
+
+                let indexes = if tmp.is_empty() { indexes.to_vec() } else { tmp };
+
+                assert!(!indexes.is_empty(), "empty explicitly set nodes");
+
+                self.select_from(selector, &indexes, true)
+            }
+
+            None => {
+                let indexes: Vec<_> = (0..self.node_ids.len())
+                    .filter(|it| self.is_node_usable(*it, now, avoid_open_circuits))
+                    .collect();
+
+                self.select_from(selector, &indexes, false)
+            }
+        };
+
+        (!selected.is_empty()).then_some(selected)
     }
-    pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
-        let mut node_ids: Vec<_> = self.healthy_node_ids().collect();
+
+    pub(crate) fn select_node_ids(
+        &self,
+        selector: &dyn NodeSelector,
+        avoid_open_circuits: bool,
+    ) -> Vec<AccountId> {
+        let now = Instant::now();
+
+        let mut indexes: Vec<_> = (0..self.node_ids.len())
+            .filter(|it| self.is_node_usable(*it, now, avoid_open_circuits))
+            .collect();
         // self.remove_dead_nodes();
 
-        if node_ids.is_empty() {
-            log::warn!("No healthy nodes, randomly picking some unhealthy ones");
+        if indexes.is_empty() {
+            log::warn!("No healthy nodes, picking some unhealthy ones");
             // hack, slowpath, don't care perf, fix this better later tho.
-            node_ids = self.node_ids.to_vec();
+            indexes = (0..self.node_ids.len()).collect();
         }
 
-        let node_sample_amount = (node_ids.len() + 2) / 3;
+        let mut selected = self.select_from(selector, &indexes, false);
 
-        let node_id_indecies =
-            rand::seq::index::sample(&mut thread_rng(), node_ids.len(), node_sample_amount);
+        if selected.is_empty() {
+            log::warn!("Node selector didn't pick any nodes, using all of them");
+            selected = indexes;
+        }
 
-        node_id_indecies.into_iter().map(|index| node_ids[index]).collect()
+        selected.into_iter().map(|it| self.node_ids[it]).collect()
     }
 
-    pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
+    /// Returns the channel for the node at `index`.
+    ///
+    /// When `transport_security` is `true` the channel uses TLS and only trusts the node's pinned certificate.
+    pub(crate) fn channel(&self, index: usize, transport_security: bool) -> (AccountId, Channel) {
         let id = self.node_ids[index];
 
-        let channel = self.connections[index].channel();
+        let channel = match transport_security {
+            true => self.connections[index].tls_channel(),
+            false => self.connections[index].channel(),
+        };
 
         (id, channel)
     }
@@ -419,6 +581,13 @@ impl NetworkData {
     }
 }
 
+#[derive(Default)]
+struct NodeState {
+    health: RwLock<NodeHealth>,
+    circuit_breaker: Mutex<CircuitBreaker>,
+    metrics: Mutex<NodeMetrics>,
+}
+
 #[derive(Default)]
 enum NodeHealth {
     /// The node has never been used, so we don't know anything about it.
@@ -519,6 +688,17 @@ impl NodeHealth {
         }
     }
 
+    fn backoff_stats(&self, now: Instant) -> Option<NodeBackoffStats> {
+        match self {
+            Self::Unhealthy { backoff, healthy_at, attempts } => Some(NodeBackoffStats {
+                current_backoff: backoff.current_interval,
+                attempts: *attempts,
+                remaining: healthy_at.saturating_duration_since(now),
+            }),
+            _ => None,
+        }
+    }
+
     pub(crate) fn recently_pinged(&self, now: Instant) -> bool {
         match self {
             // when used at was less than 15 minutes ago we consider ourselves "pinged", otherwise we're basically `.unused`.
@@ -569,22 +749,50 @@ impl From<Ipv4Addr> for HostAndPort {
     }
 }
 
+impl From<SocketAddrV4> for HostAndPort {
+    fn from(value: SocketAddrV4) -> Self {
+        Self { host: Cow::Owned(value.ip().to_string()), port: value.port() }
+    }
+}
+
 #[derive(Clone)]
 struct NodeConnection {
+    /// The plaintext addresses of the node.
     addresses: BTreeSet<HostAndPort>,
+    /// The TLS addresses of the node, in addition to the TLS port of each of `addresses`.
+    tls_addresses: BTreeSet<HostAndPort>,
+    /// The hash of the node's TLS certificate, as given by the address book.
+    tls_certificate_hash: Option<Vec<u8>>,
     channel: OnceCell<Channel>,
+    tls_channel: OnceCell<Channel>,
 }
 
 impl NodeConnection {
     const PLAINTEXT_PORT: u16 = 50211;
+    const TLS_PORT: u16 = 50212;
 
-    fn new_static(addresses: &[&'static str]) -> NodeConnection {
+    fn new(
+        addresses: BTreeSet<HostAndPort>,
+        tls_addresses: BTreeSet<HostAndPort>,
+        tls_certificate_hash: Option<Vec<u8>>,
+    ) -> Self {
         Self {
-            addresses: addresses.iter().copied().map(HostAndPort::from_static).collect(),
-            channel: OnceCell::default(),
+            addresses,
+            tls_addresses,
+            tls_certificate_hash,
+            channel: OnceCell::new(),
+            tls_channel: OnceCell::new(),
         }
     }
 
+    fn new_static(addresses: &[&'static str]) -> NodeConnection {
+        Self::new(
+            addresses.iter().copied().map(HostAndPort::from_static).collect(),
+            BTreeSet::new(),
+            None,
+        )
+    }
+
     pub(crate) fn channel(&self) -> Channel {
         let channel = self
             .channel
@@ -604,4 +812,91 @@ impl NodeConnection {
 
         channel
     }
+
+    fn tls_addresses(&self) -> Vec<HostAndPort> {
+        // the plaintext port gets swapped out for the TLS port, anything else is assumed to already be a TLS port.
+        let addresses: BTreeSet<_> = self
+            .addresses
+            .iter()
+            .map(|it| match it.port {
+                Self::PLAINTEXT_PORT => HostAndPort { port: Self::TLS_PORT, ..it.clone() },
+                _ => it.clone(),
+            })
+            .chain(self.tls_addresses.iter().cloned())
+            .collect();
+
+        addresses.into_iter().collect()
+    }
+
+    pub(crate) fn tls_channel(&self) -> Channel {
+        self.tls_channel
+            .get_or_init(|| {
+                let addresses = self.tls_addresses();
+
+                // note: the scheme is `tcp` rather than `https` because TLS is handled by the connector, not by tonic.
+                // if there's no address at all the connector will error out, so the uri doesn't matter.
+                let uri = match addresses.first() {
+                    Some(it) => format!("tcp://{it}"),
+                    None => format!("tcp://0.0.0.0:{}", Self::TLS_PORT),
+                };
+
+                let endpoint = Endpoint::from_shared(uri)
+                    .unwrap()
+                    .keep_alive_timeout(Duration::from_secs(10))
+                    .keep_alive_while_idle(true)
+                    .tcp_keepalive(Some(Duration::from_secs(10)))
+                    .connect_timeout(Duration::from_secs(10));
+
+                endpoint.connect_with_connector_lazy(tls::TlsConnector::new(
+                    addresses,
+                    self.tls_certificate_hash.clone(),
+                ))
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+
+    use super::NetworkData;
+    use crate::{
+        AccountId,
+        NodeAddress,
+        NodeAddressBook,
+    };
+
+    #[test]
+    fn address_book_splits_plaintext_and_tls_ports() {
+        let ip = Ipv4Addr::new(10, 0, 0, 1);
+        let tls_only_ip = Ipv4Addr::new(10, 0, 0, 2);
+
+        let address_book = NodeAddressBook {
+            node_addresses: Vec::from([NodeAddress {
+                node_id: 0,
+                rsa_public_key: Vec::new(),
+                node_account_id: AccountId::from(3),
+                tls_certificate_hash: Vec::new(),
+                service_endpoints: Vec::from([
+                    SocketAddrV4::new(ip, 50211),
+                    SocketAddrV4::new(ip, 50212),
+                    SocketAddrV4::new(tls_only_ip, 50212),
+                ]),
+                description: String::new(),
+            }]),
+        };
+
+        let network = NetworkData::with_address_book(&NetworkData::default(), &address_book);
+        let connection = &network.connections[0];
+
+        let plaintext: Vec<_> = connection.addresses.iter().map(ToString::to_string).collect();
+        assert_eq!(plaintext, ["10.0.0.1:50211"]);
+
+        let tls: Vec<_> = connection.tls_addresses().iter().map(ToString::to_string).collect();
+        assert_eq!(tls, ["10.0.0.1:50212", "10.0.0.2:50212"]);
+    }
 }
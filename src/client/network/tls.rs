@@ -0,0 +1,262 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! TLS transport for consensus nodes.
+//!
+//! Consensus nodes don't present certificates signed by a public CA,
+//! instead, each node's certificate is pinned by the hash published for it in the address book.
+
+use std::io;
+use std::task::{
+    Context,
+    Poll,
+};
+
+use futures_core::future::BoxFuture;
+use rustls::client::danger::{
+    HandshakeSignatureValid,
+    ServerCertVerified,
+    ServerCertVerifier,
+};
+use rustls::crypto::{
+    verify_tls12_signature,
+    verify_tls13_signature,
+    CryptoProvider,
+};
+use rustls::pki_types::{
+    CertificateDer,
+    ServerName,
+    UnixTime,
+};
+use rustls::{
+    DigitallySignedStruct,
+    SignatureScheme,
+};
+use sha2::{
+    Digest,
+    Sha384,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tonic::transport::Uri;
+
+use super::HostAndPort;
+
+/// Returns `true` if the hash of `certificate` matches `expected_hash`.
+///
+/// `expected_hash` is the `node_cert_hash` from the address book, which is
+/// the SHA-384 hash of the certificate in PEM format, usually encoded as hex characters.
+pub(super) fn certificate_hash_matches(expected_hash: &[u8], certificate: &[u8]) -> bool {
+    let pem = pem::encode_config(
+        &pem::Pem::new("CERTIFICATE", certificate),
+        pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
+    );
+
+    let actual = Sha384::digest(pem.as_bytes());
+
+    // the address book *should* give us hex, but there's nothing stopping a raw hash from showing up.
+    if expected_hash.len() == actual.len() {
+        return expected_hash == actual.as_slice();
+    }
+
+    std::str::from_utf8(expected_hash)
+        .ok()
+        .and_then(|it| hex::decode(it.trim()).ok())
+        .map_or(false, |it| it == actual.as_slice())
+}
+
+/// Accepts a node's certificate if and only if it matches the pinned hash.
+#[derive(Debug)]
+struct CertificateHashVerifier {
+    tls_certificate_hash: Option<Vec<u8>>,
+    provider: CryptoProvider,
+}
+
+impl ServerCertVerifier for CertificateHashVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        let Some(expected) = self.tls_certificate_hash.as_deref() else {
+            return Err(rustls::Error::General(
+                "no TLS certificate hash is known for this node".to_owned(),
+            ));
+        };
+
+        if !certificate_hash_matches(expected, end_entity) {
+            return Err(rustls::Error::General(
+                "node TLS certificate hash doesn't match the address book".to_owned(),
+            ));
+        }
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Connects to a node over TLS, trying each of the node's addresses in order.
+///
+/// The `Uri` given by tonic is ignored, it's only there to satisfy `Endpoint`.
+#[derive(Clone)]
+pub(super) struct TlsConnector {
+    addresses: triomphe::Arc<[HostAndPort]>,
+    // rustls wants a `std` Arc.
+    #[allow(clippy::disallowed_types)]
+    config: std::sync::Arc<rustls::ClientConfig>,
+}
+
+impl TlsConnector {
+    #[allow(clippy::disallowed_types)]
+    pub(super) fn new(addresses: Vec<HostAndPort>, tls_certificate_hash: Option<Vec<u8>>) -> Self {
+        let verifier = CertificateHashVerifier {
+            tls_certificate_hash,
+            provider: rustls::crypto::ring::default_provider(),
+        };
+
+        let mut config = rustls::ClientConfig::builder()
+            .dangerous()
+            .with_custom_certificate_verifier(std::sync::Arc::new(verifier))
+            .with_no_client_auth();
+
+        // gRPC is HTTP/2 only.
+        config.alpn_protocols = Vec::from([b"h2".to_vec()]);
+
+        Self { addresses: addresses.into(), config: std::sync::Arc::new(config) }
+    }
+
+    async fn connect(self) -> io::Result<TlsStream<TcpStream>> {
+        let connector = tokio_rustls::TlsConnector::from(self.config.clone());
+
+        let mut last_error = None;
+
+        for address in self.addresses.iter() {
+            let result = async {
+                let server_name = ServerName::try_from(address.host.to_string())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+                let stream = TcpStream::connect((&*address.host, address.port)).await?;
+                stream.set_nodelay(true)?;
+
+                connector.connect(server_name, stream).await
+            }
+            .await;
+
+            match result {
+                Ok(stream) => return Ok(stream),
+                Err(e) => {
+                    log::debug!("failed to connect to node at {address} over TLS: {e}");
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "node has no addresses")))
+    }
+}
+
+impl tower_service::Service<Uri> for TlsConnector {
+    type Response = TlsStream<TcpStream>;
+    type Error = io::Error;
+    type Future = BoxFuture<'static, io::Result<TlsStream<TcpStream>>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        Box::pin(self.clone().connect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{
+        Digest,
+        Sha384,
+    };
+
+    use super::certificate_hash_matches;
+
+    const CERTIFICATE: &[u8] = b"not really a certificate, but the hash doesn't care";
+
+    fn pem_hash() -> Vec<u8> {
+        let pem = pem::encode_config(
+            &pem::Pem::new("CERTIFICATE", CERTIFICATE),
+            pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
+        );
+
+        assert!(pem.starts_with("-----BEGIN CERTIFICATE-----\n"));
+        assert!(pem.ends_with("-----END CERTIFICATE-----\n"));
+
+        Sha384::digest(pem.as_bytes()).to_vec()
+    }
+
+    #[test]
+    fn matches_hex_hash() {
+        let hash = hex::encode(pem_hash());
+
+        assert!(certificate_hash_matches(hash.as_bytes(), CERTIFICATE));
+        assert!(certificate_hash_matches(hash.to_uppercase().as_bytes(), CERTIFICATE));
+    }
+
+    #[test]
+    fn matches_raw_hash() {
+        assert!(certificate_hash_matches(&pem_hash(), CERTIFICATE));
+    }
+
+    #[test]
+    fn rejects_other_certificate() {
+        let hash = hex::encode(pem_hash());
+
+        assert!(!certificate_hash_matches(hash.as_bytes(), b"some other certificate"));
+    }
+
+    #[test]
+    fn rejects_garbage_hash() {
+        assert!(!certificate_hash_matches(b"", CERTIFICATE));
+        assert!(!certificate_hash_matches(b"not hex", CERTIFICATE));
+    }
+}
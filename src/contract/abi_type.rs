@@ -0,0 +1,333 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// The type of a Solidity ABI value, such as `uint256`, `address[]`, or `(address,uint256)[]`.
+///
+/// Solidity structs are tuples of their fields' types.
+///
+/// Parsed from, and displayed as, the canonical type name that function selectors are computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiType {
+    /// `address`
+    Address,
+
+    /// `bool`
+    Bool,
+
+    /// `intN`, with the size in bits (8 to 256, in steps of 8).
+    Int(u16),
+
+    /// `uintN`, with the size in bits (8 to 256, in steps of 8).
+    Uint(u16),
+
+    /// `bytesN`, with the size in bytes (1 to 32).
+    FixedBytes(u8),
+
+    /// `bytes`
+    Bytes,
+
+    /// `string`
+    String,
+
+    /// `T[]`
+    Array(Box<AbiType>),
+
+    /// `T[N]`
+    FixedArray(Box<AbiType>, usize),
+
+    /// `(T1,T2,...)`, such as a struct.
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns true if values of this type are encoded out of line (after the static parts of the encoding).
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// The number of bytes values of this type take up in the head of an encoding.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if that doesn't fit in a `usize`, such as for a huge fixed array.
+    pub(crate) fn head_size(&self) -> crate::Result<usize> {
+        if self.is_dynamic() {
+            return Ok(32);
+        }
+
+        let too_large = || Error::abi(format!("ABI type `{self}` is too large"));
+
+        match self {
+            Self::FixedArray(inner, len) => {
+                inner.head_size()?.checked_mul(*len).ok_or_else(too_large)
+            }
+            Self::Tuple(types) => types.iter().try_fold(0_usize, |size, ty| {
+                size.checked_add(ty.head_size()?).ok_or_else(too_large)
+            }),
+            _ => Ok(32),
+        }
+    }
+
+    /// Check that this type can be encoded, which types parsed from strings always can.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if an integer isn't 8 to 256 bits in steps of 8, if a `bytesN` isn't 1 to 32 bytes,
+    ///   or if the type is too large to encode.
+    pub(crate) fn validate(&self) -> crate::Result<()> {
+        match self {
+            Self::Int(bits) | Self::Uint(bits) if !is_valid_int_bits(*bits) => Err(Error::abi(
+                format!("invalid ABI type `{self}`, integers must be 8 to 256 bits in steps of 8"),
+            )),
+            Self::FixedBytes(size) if !(1..=32).contains(size) => Err(Error::abi(format!(
+                "invalid ABI type `{self}`, `bytesN` must be 1 to 32 bytes"
+            ))),
+            Self::Array(inner) => inner.validate(),
+            Self::FixedArray(inner, _) => {
+                inner.validate()?;
+                self.head_size().map(drop)
+            }
+            Self::Tuple(types) => {
+                types.iter().try_for_each(Self::validate)?;
+                self.head_size().map(drop)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_valid_int_bits(bits: u16) -> bool {
+    bits % 8 == 0 && (8..=256).contains(&bits)
+}
+
+impl FromStr for AbiType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let unknown = || Error::basic_parse(format!("unknown ABI type `{s}`"));
+
+        if let Some(inner) = s.strip_suffix(']') {
+            let (element, len) = inner.rsplit_once('[').ok_or_else(unknown)?;
+            let element = Box::new(element.parse()?);
+
+            return match len {
+                "" => Ok(Self::Array(element)),
+                len => Ok(Self::FixedArray(element, len.parse().map_err(|_| unknown())?)),
+            };
+        }
+
+        if let Some(inner) = s.strip_prefix('(').and_then(|it| it.strip_suffix(')')) {
+            let types = split_tuple(inner).ok_or_else(unknown)?.map(str::parse);
+            return types.collect::<crate::Result<_>>().map(Self::Tuple);
+        }
+
+        let bits = |bits: &str| match bits {
+            // `int` and `uint` are aliases for `int256` and `uint256`.
+            "" => Some(256),
+            bits => bits.parse().ok().filter(|it| is_valid_int_bits(*it)),
+        };
+
+        match s {
+            "address" => Ok(Self::Address),
+            "bool" => Ok(Self::Bool),
+            "bytes" => Ok(Self::Bytes),
+            "string" => Ok(Self::String),
+            _ => {
+                if let Some(size) = s.strip_prefix("bytes") {
+                    let size = size.parse().ok().filter(|it| (1..=32).contains(it));
+                    return size.map(Self::FixedBytes).ok_or_else(unknown);
+                }
+
+                if let Some(size) = s.strip_prefix("int") {
+                    return bits(size).map(Self::Int).ok_or_else(unknown);
+                }
+
+                if let Some(size) = s.strip_prefix("uint") {
+                    return bits(size).map(Self::Uint).ok_or_else(unknown);
+                }
+
+                Err(unknown())
+            }
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(size) => write!(f, "bytes{size}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Array(inner) => write!(f, "{inner}[]"),
+            Self::FixedArray(inner, len) => write!(f, "{inner}[{len}]"),
+            Self::Tuple(types) => {
+                f.write_str("(")?;
+                for (index, ty) in types.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{ty}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// Splits the inside of a tuple type on its top level commas, `None` if its parentheses are unbalanced.
+fn split_tuple(inner: &str) -> Option<impl Iterator<Item = &str>> {
+    let mut depth = 0_usize;
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    for (index, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                parts.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return None;
+    }
+
+    // `()` is the empty tuple, rather than a tuple of one empty type.
+    if !inner.is_empty() {
+        parts.push(&inner[start..]);
+    }
+
+    Some(parts.into_iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::AbiType;
+    use crate::Error;
+
+    #[test]
+    fn parse() {
+        assert_eq!("uint".parse::<AbiType>().unwrap(), AbiType::Uint(256));
+        assert_eq!("int8".parse::<AbiType>().unwrap(), AbiType::Int(8));
+        assert_eq!("bytes4".parse::<AbiType>().unwrap(), AbiType::FixedBytes(4));
+        assert_eq!(
+            "address[2][]".parse::<AbiType>().unwrap(),
+            AbiType::Array(Box::new(AbiType::FixedArray(Box::new(AbiType::Address), 2)))
+        );
+
+        assert_eq!(
+            "(address,(uint256,bool[]))[]".parse::<AbiType>().unwrap(),
+            AbiType::Array(Box::new(AbiType::Tuple(Vec::from([
+                AbiType::Address,
+                AbiType::Tuple(Vec::from([
+                    AbiType::Uint(256),
+                    AbiType::Array(Box::new(AbiType::Bool))
+                ])),
+            ]))))
+        );
+        assert_eq!("()".parse::<AbiType>().unwrap(), AbiType::Tuple(Vec::new()));
+
+        for ty in [
+            "uint7",
+            "uint264",
+            "bytes0",
+            "bytes33",
+            "int[",
+            "string[x]",
+            "function",
+            "(uint,)",
+            "(bool))",
+            "((bool)",
+        ] {
+            assert!(ty.parse::<AbiType>().is_err(), "{ty}");
+        }
+    }
+
+    #[test]
+    fn display_is_canonical() {
+        for ty in [
+            "uint256",
+            "int8",
+            "bytes32",
+            "bool",
+            "string",
+            "bytes",
+            "address[3][]",
+            "(address,(bytes,int8)[2])",
+        ] {
+            assert_eq!(ty.parse::<AbiType>().unwrap().to_string(), ty);
+        }
+
+        assert_eq!("uint[]".parse::<AbiType>().unwrap().to_string(), "uint256[]");
+        assert_eq!("(uint,int)".parse::<AbiType>().unwrap().to_string(), "(uint256,int256)");
+    }
+
+    #[test]
+    fn validate() {
+        for ty in ["uint8", "int256", "bytes1", "bytes32", "(uint256,bytes)[2][]"] {
+            assert!(ty.parse::<AbiType>().unwrap().validate().is_ok(), "{ty}");
+        }
+
+        let huge = AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX);
+
+        for ty in [
+            AbiType::Int(0),
+            AbiType::Int(7),
+            AbiType::Uint(264),
+            AbiType::FixedBytes(0),
+            AbiType::FixedBytes(33),
+            AbiType::Array(Box::new(AbiType::Uint(0))),
+            AbiType::Tuple(Vec::from([AbiType::Bool, AbiType::FixedBytes(64)])),
+            huge.clone(),
+            AbiType::Tuple(Vec::from([huge, AbiType::Bool])),
+        ] {
+            assert_matches!(ty.validate(), Err(Error::Abi(_)), "{ty}");
+        }
+    }
+
+    #[test]
+    fn head_size_overflow() {
+        let ty = AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX / 32 + 1);
+        assert_matches!(ty.head_size(), Err(Error::Abi(_)));
+
+        let ty = AbiType::FixedArray(Box::new(AbiType::Uint(256)), 3);
+        assert_eq!(ty.head_size().unwrap(), 96);
+    }
+}
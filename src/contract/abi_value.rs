@@ -0,0 +1,675 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+use crate::{
+    AbiType,
+    Error,
+    EvmAddress,
+};
+
+const WORD: usize = 32;
+
+/// A Solidity ABI value, such as a function argument or return value.
+///
+/// Integers are arbitrary precision, they're checked against the size of their [`AbiType`] when encoding.
+/// An `int` value can be encoded as a `uint` (and vice versa) as long as it fits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiValue {
+    /// `address`
+    Address(EvmAddress),
+
+    /// `bool`
+    Bool(bool),
+
+    /// `intN`
+    Int(BigInt),
+
+    /// `uintN`
+    Uint(BigUint),
+
+    /// `bytesN`
+    FixedBytes(Vec<u8>),
+
+    /// `bytes`
+    Bytes(Vec<u8>),
+
+    /// `string`
+    String(String),
+
+    /// `T[]`
+    Array(Vec<AbiValue>),
+
+    /// `T[N]`
+    FixedArray(Vec<AbiValue>),
+
+    /// `(T1,T2,...)`, such as a struct, with its fields in order.
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// ABI encode `values`, as the parameters of a function call (without the selector).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the number of values doesn't match the number of types,
+    ///   if any type is invalid (such as `Int(0)`), or if any value doesn't fit its type.
+    pub fn encode(types: &[AbiType], values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        if types.len() != values.len() {
+            return Err(Error::abi(format!(
+                "expected {} values, found {}",
+                types.len(),
+                values.len()
+            )));
+        }
+
+        types.iter().try_for_each(AbiType::validate)?;
+
+        let mut out = Vec::new();
+        encode_sequence(types.iter().zip(values), &mut out)?;
+        Ok(out)
+    }
+
+    /// Decode ABI encoded `data`, such as the return value of a function call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if any type is invalid (such as `FixedBytes(33)`), or if `data` isn't a valid encoding of `types`.
+    pub fn decode(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        types.iter().try_for_each(AbiType::validate)?;
+
+        decode_sequence(types.iter(), data)
+    }
+
+    /// Decode a single value of type `ty` from a 32 byte `word`, such as an indexed event parameter.
+    pub(crate) fn decode_word(ty: &AbiType, word: &[u8]) -> crate::Result<AbiValue> {
+        ty.validate()?;
+        decode_value(ty, word)
+    }
+
+    /// Encode a single value on its own, the tail of it for dynamic types, or its head otherwise.
+    pub(crate) fn encode_single(ty: &AbiType, value: &AbiValue) -> crate::Result<Vec<u8>> {
+        ty.validate()?;
+
+        let mut out = Vec::new();
+        encode_value(ty, value, &mut out)?;
+        Ok(out)
+    }
+
+    /// Decode a single value of type `ty` whose head is at `offset` in `data`.
+    pub(crate) fn decode_at(ty: &AbiType, data: &[u8], offset: usize) -> crate::Result<AbiValue> {
+        ty.validate()?;
+        decode_item(ty, data, offset)
+    }
+
+    /// Returns the value of a `bool`.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an `address`.
+    #[must_use]
+    pub fn as_address(&self) -> Option<EvmAddress> {
+        match self {
+            Self::Address(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an `intN` or `uintN`.
+    #[must_use]
+    pub fn as_int(&self) -> Option<BigInt> {
+        match self {
+            Self::Int(it) => Some(it.clone()),
+            Self::Uint(it) => Some(BigInt::from(it.clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `uintN`, or of a non-negative `intN`.
+    #[must_use]
+    pub fn as_uint(&self) -> Option<BigUint> {
+        match self {
+            Self::Uint(it) => Some(it.clone()),
+            Self::Int(it) => it.to_biguint(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `bytes` or `bytesN`.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(it) | Self::FixedBytes(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a `string`.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a `T[]` or `T[N]`.
+    #[must_use]
+    pub fn as_array(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Array(it) | Self::FixedArray(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of a tuple (or struct).
+    #[must_use]
+    pub fn as_tuple(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Tuple(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AbiValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address(it) => write!(f, "{it}"),
+            Self::Bool(it) => write!(f, "{it}"),
+            Self::Int(it) => write!(f, "{it}"),
+            Self::Uint(it) => write!(f, "{it}"),
+            Self::FixedBytes(it) | Self::Bytes(it) => write!(f, "0x{}", hex::encode(it)),
+            Self::String(it) => write!(f, "{it:?}"),
+            Self::Array(it) | Self::FixedArray(it) => write_list(f, "[", it, "]"),
+            Self::Tuple(it) => write_list(f, "(", it, ")"),
+        }
+    }
+}
+
+fn write_list(
+    f: &mut fmt::Formatter<'_>,
+    open: &str,
+    items: &[AbiValue],
+    close: &str,
+) -> fmt::Result {
+    f.write_str(open)?;
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{item}")?;
+    }
+    f.write_str(close)
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<EvmAddress> for AbiValue {
+    fn from(value: EvmAddress) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<BigInt> for AbiValue {
+    fn from(value: BigInt) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        Self::Uint(value)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $big:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant($big::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Uint, BigUint: u8, u16, u32, u64, u128);
+impl_from_int!(Int, BigInt: i8, i16, i32, i64, i128);
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for AbiValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for AbiValue {
+    fn from(value: [u8; N]) -> Self {
+        Self::FixedBytes(value.to_vec())
+    }
+}
+
+fn mismatch(ty: &AbiType, value: &AbiValue) -> Error {
+    Error::abi(format!("value `{value}` doesn't fit type `{ty}`"))
+}
+
+/// Encodes a tuple (or the elements of an array), the heads of every value followed by the tails of the dynamic ones.
+fn encode_sequence<'a>(
+    items: impl Iterator<Item = (&'a AbiType, &'a AbiValue)> + Clone,
+    out: &mut Vec<u8>,
+) -> crate::Result<()> {
+    let start = out.len();
+    let heads_size = items.clone().try_fold(0_usize, |size, (ty, _)| {
+        size.checked_add(ty.head_size()?).ok_or_else(|| Error::abi("encoding is too large"))
+    })?;
+
+    let mut tails = Vec::new();
+
+    for (ty, value) in items {
+        if ty.is_dynamic() {
+            out.extend_from_slice(&encode_usize(heads_size + tails.len()));
+            encode_value(ty, value, &mut tails)?;
+        } else {
+            encode_value(ty, value, out)?;
+        }
+    }
+
+    debug_assert_eq!(out.len() - start, heads_size);
+
+    out.append(&mut tails);
+
+    Ok(())
+}
+
+fn encode_value(ty: &AbiType, value: &AbiValue, out: &mut Vec<u8>) -> crate::Result<()> {
+    match (ty, value) {
+        (AbiType::Address, AbiValue::Address(address)) => {
+            out.extend_from_slice(&[0; 12]);
+            out.extend_from_slice(&address.to_bytes());
+        }
+        (AbiType::Bool, AbiValue::Bool(it)) => {
+            out.extend_from_slice(&encode_usize(usize::from(*it)));
+        }
+        (AbiType::Int(bits), AbiValue::Int(_) | AbiValue::Uint(_)) => {
+            let value = value.as_int().expect("checked to be an integer above");
+            let limit = BigInt::from(1) << (bits - 1);
+
+            if value < -limit.clone() || value >= limit {
+                return Err(mismatch(ty, &AbiValue::Int(value)));
+            }
+
+            out.extend_from_slice(&encode_int(&value));
+        }
+        (AbiType::Uint(bits), AbiValue::Int(_) | AbiValue::Uint(_)) => {
+            let uint = value.as_uint().filter(|it| it.bits() <= u64::from(*bits));
+            let uint = uint.ok_or_else(|| mismatch(ty, value))?;
+
+            out.extend_from_slice(&encode_int(&BigInt::from(uint)));
+        }
+        (AbiType::FixedBytes(size), AbiValue::FixedBytes(bytes) | AbiValue::Bytes(bytes))
+            if bytes.len() == usize::from(*size) =>
+        {
+            out.extend_from_slice(&pad_right(bytes));
+        }
+        (AbiType::Bytes, AbiValue::Bytes(bytes) | AbiValue::FixedBytes(bytes)) => {
+            out.extend_from_slice(&encode_usize(bytes.len()));
+            out.extend_from_slice(&pad_right(bytes));
+        }
+        (AbiType::String, AbiValue::String(s)) => {
+            out.extend_from_slice(&encode_usize(s.len()));
+            out.extend_from_slice(&pad_right(s.as_bytes()));
+        }
+        (AbiType::Array(inner), AbiValue::Array(items) | AbiValue::FixedArray(items)) => {
+            out.extend_from_slice(&encode_usize(items.len()));
+            encode_sequence(items.iter().map(|it| (&**inner, it)), out)?;
+        }
+        (AbiType::FixedArray(inner, len), AbiValue::FixedArray(items) | AbiValue::Array(items))
+            if items.len() == *len =>
+        {
+            encode_sequence(items.iter().map(|it| (&**inner, it)), out)?;
+        }
+        (AbiType::Tuple(types), AbiValue::Tuple(items)) if items.len() == types.len() => {
+            encode_sequence(types.iter().zip(items), out)?;
+        }
+        _ => return Err(mismatch(ty, value)),
+    }
+
+    Ok(())
+}
+
+fn encode_usize(value: usize) -> [u8; WORD] {
+    let mut out = [0; WORD];
+    out[WORD - 8..].copy_from_slice(&(value as u64).to_be_bytes());
+    out
+}
+
+/// Two's complement, sign extended to 256 bits, `value` must already be in range.
+fn encode_int(value: &BigInt) -> [u8; WORD] {
+    let (fill, bytes) = match value.sign() {
+        Sign::Minus => (0xff, value.to_signed_bytes_be()),
+        _ => (0, value.to_bytes_be().1),
+    };
+
+    let mut out = [fill; WORD];
+    out[WORD - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+/// `bytes` padded with zeros to a multiple of 32 bytes.
+fn pad_right(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    out.resize(bytes.len().div_ceil(WORD) * WORD, 0);
+    out
+}
+
+fn too_short() -> Error {
+    Error::abi("data is too short")
+}
+
+fn word(data: &[u8], offset: usize) -> crate::Result<&[u8]> {
+    data.get(offset..).and_then(|it| it.get(..WORD)).ok_or_else(too_short)
+}
+
+/// Reads a length or offset, which has to fit in a `usize` (and really, in the data).
+fn decode_usize(data: &[u8], offset: usize) -> crate::Result<usize> {
+    let word = word(data, offset)?;
+
+    let (high, low) = word.split_at(WORD - 8);
+    let value = u64::from_be_bytes(low.try_into().unwrap());
+
+    if high.iter().any(|it| *it != 0) || value > data.len() as u64 {
+        return Err(Error::abi(format!("invalid length or offset `0x{}`", hex::encode(word))));
+    }
+
+    Ok(value as usize)
+}
+
+/// Decodes a tuple (or the elements of an array), `data` starts at the tuple's first head.
+fn decode_sequence<'a>(
+    types: impl Iterator<Item = &'a AbiType>,
+    data: &[u8],
+) -> crate::Result<Vec<AbiValue>> {
+    let mut offset = 0;
+
+    types
+        .map(|ty| {
+            let value = decode_item(ty, data, offset)?;
+            offset = offset.checked_add(ty.head_size()?).ok_or_else(too_short)?;
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Decodes the item of a tuple (or array) whose head is at `offset` in `data`.
+fn decode_item(ty: &AbiType, data: &[u8], offset: usize) -> crate::Result<AbiValue> {
+    if ty.is_dynamic() {
+        let tail = decode_usize(data, offset)?;
+        decode_value(ty, &data[tail..])
+    } else {
+        decode_value(ty, data.get(offset..).ok_or_else(too_short)?)
+    }
+}
+
+fn decode_value(ty: &AbiType, data: &[u8]) -> crate::Result<AbiValue> {
+    // tuples and fixed arrays can be empty, so they can't require a first word.
+    let first = || word(data, 0);
+
+    let value = match ty {
+        AbiType::Address => {
+            AbiValue::Address(EvmAddress::from(<[u8; 20]>::try_from(&first()?[12..]).unwrap()))
+        }
+        AbiType::Bool => AbiValue::Bool(first()?.iter().any(|it| *it != 0)),
+        AbiType::Int(_) => AbiValue::Int(BigInt::from_signed_bytes_be(first()?)),
+        AbiType::Uint(_) => AbiValue::Uint(BigUint::from_bytes_be(first()?)),
+        AbiType::FixedBytes(size) => AbiValue::FixedBytes(first()?[..usize::from(*size)].to_vec()),
+        AbiType::Bytes | AbiType::String => {
+            let len = decode_usize(data, 0)?;
+            let bytes = data.get(WORD..).and_then(|it| it.get(..len)).ok_or_else(too_short)?;
+
+            match ty {
+                AbiType::String => {
+                    AbiValue::String(String::from_utf8(bytes.to_vec()).map_err(Error::abi)?)
+                }
+                _ => AbiValue::Bytes(bytes.to_vec()),
+            }
+        }
+        AbiType::Array(inner) => {
+            let len = decode_usize(data, 0)?;
+
+            // every element takes up at least one word, so this can't allocate more than `data` is long.
+            if len > data.len() / WORD {
+                return Err(too_short());
+            }
+
+            let items = std::iter::repeat(&**inner).take(len);
+            AbiValue::Array(decode_sequence(items, &data[WORD..])?)
+        }
+        AbiType::FixedArray(inner, len) => {
+            let items = std::iter::repeat(&**inner).take(*len);
+            AbiValue::FixedArray(decode_sequence(items, data)?)
+        }
+        AbiType::Tuple(types) => AbiValue::Tuple(decode_sequence(types.iter(), data)?),
+    };
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use num_bigint::BigInt;
+
+    use super::AbiValue;
+    use crate::{
+        AbiType,
+        Error,
+        EvmAddress,
+    };
+
+    fn types(types: &[&str]) -> Vec<AbiType> {
+        types.iter().map(|it| it.parse().unwrap()).collect()
+    }
+
+    // from the Solidity ABI specification's examples.
+    #[test]
+    fn dynamic_types() {
+        let types = types(&["uint256", "uint32[]", "bytes10", "bytes"]);
+        let values = [
+            AbiValue::from(0x123_u32),
+            AbiValue::Array(Vec::from([0x456_u32.into(), 0x789_u32.into()])),
+            AbiValue::from(*b"1234567890"),
+            AbiValue::from(b"Hello, world!".to_vec()),
+        ];
+
+        let encoded = AbiValue::encode(&types, &values).unwrap();
+
+        assert_eq!(
+            encoded,
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000123"
+                "0000000000000000000000000000000000000000000000000000000000000080"
+                "3132333435363738393000000000000000000000000000000000000000000000"
+                "00000000000000000000000000000000000000000000000000000000000000e0"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000456"
+                "0000000000000000000000000000000000000000000000000000000000000789"
+                "000000000000000000000000000000000000000000000000000000000000000d"
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000"
+            )
+        );
+
+        assert_eq!(AbiValue::decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn nested_arrays() {
+        let types = types(&["uint256[][]", "string[2]"]);
+        let values = [
+            AbiValue::Array(Vec::from([
+                AbiValue::Array(Vec::from([1_u8.into(), 2_u8.into()])),
+                AbiValue::Array(Vec::from([3_u8.into()])),
+            ])),
+            AbiValue::FixedArray(Vec::from(["one".into(), "two".into()])),
+        ];
+
+        let encoded = AbiValue::encode(&types, &values).unwrap();
+
+        assert_eq!(AbiValue::decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn tuples() {
+        let types = types(&["(address,uint256)", "(uint256,bytes)", "(bool,string)[]"]);
+        let values = [
+            AbiValue::Tuple(Vec::from([
+                EvmAddress::from(hex!("00000000000000000000000000000000000003e9")).into(),
+                5_u8.into(),
+            ])),
+            AbiValue::Tuple(Vec::from([1_u8.into(), hex!("abcd").to_vec().into()])),
+            AbiValue::Array(Vec::from([
+                AbiValue::Tuple(Vec::from([true.into(), "yes".into()])),
+                AbiValue::Tuple(Vec::from([false.into(), "no".into()])),
+            ])),
+        ];
+
+        let encoded = AbiValue::encode(&types[..2], &values[..2]).unwrap();
+
+        assert_eq!(
+            encoded,
+            hex!(
+                "00000000000000000000000000000000000000000000000000000000000003e9"
+                "0000000000000000000000000000000000000000000000000000000000000005"
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "abcd000000000000000000000000000000000000000000000000000000000000"
+            )
+        );
+
+        let encoded = AbiValue::encode(&types, &values).unwrap();
+        assert_eq!(AbiValue::decode(&types, &encoded).unwrap(), values);
+
+        assert_eq!(values[2].to_string(), r#"[(true, "yes"), (false, "no")]"#);
+
+        // wrong number of fields.
+        assert!(
+            AbiValue::encode(&types[..1], &[AbiValue::Tuple(Vec::from([5_u8.into()]))]).is_err()
+        );
+    }
+
+    #[test]
+    fn int_ranges() {
+        let int8 = types(&["int8"]);
+        let uint8 = types(&["uint8"]);
+
+        assert_eq!(
+            AbiValue::encode(&int8, &[(-1_i8).into()]).unwrap(),
+            hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+        );
+        assert_eq!(
+            AbiValue::decode(&int8, &[0xff; 32]).unwrap(),
+            [AbiValue::Int(BigInt::from(-1))]
+        );
+
+        assert!(AbiValue::encode(&int8, &[128_u32.into()]).is_err());
+        assert!(AbiValue::encode(&int8, &[(-129_i32).into()]).is_err());
+        assert!(AbiValue::encode(&uint8, &[255_i32.into()]).is_ok());
+        assert!(AbiValue::encode(&uint8, &[256_u32.into()]).is_err());
+        assert!(AbiValue::encode(&uint8, &[(-1_i32).into()]).is_err());
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let types = types(&["bytes"]);
+
+        // offset past the end.
+        assert!(AbiValue::decode(
+            &types,
+            &hex!("0000000000000000000000000000000000000000000000000000000000000040")
+        )
+        .is_err());
+
+        // huge length.
+        assert!(AbiValue::decode(
+            &types,
+            &hex!(
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "00000000000000000000000000000000000000000000000000000000ffffffff"
+            )
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_types() {
+        let word = [0; 32];
+
+        for ty in [
+            AbiType::Int(0),
+            AbiType::Uint(300),
+            AbiType::FixedBytes(33),
+            AbiType::FixedArray(Box::new(AbiType::FixedBytes(0)), 1),
+            AbiType::FixedArray(
+                Box::new(AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX)),
+                2,
+            ),
+        ] {
+            let types = [ty];
+
+            assert_matches!(
+                AbiValue::encode(&types, &[AbiValue::Uint(0_u8.into())]),
+                Err(Error::Abi(_)),
+                "{}",
+                types[0]
+            );
+            assert_matches!(AbiValue::decode(&types, &word), Err(Error::Abi(_)), "{}", types[0]);
+        }
+    }
+}
@@ -0,0 +1,708 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::contract::contract_function_selector::ContractFunctionSelector;
+use crate::{
+    AbiType,
+    AbiValue,
+    Error,
+};
+
+/// A parameter of an [`AbiFunction`], [`AbiError`], or [`AbiEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AbiParam {
+    /// The name of the parameter, which may be empty.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub ty: AbiType,
+
+    /// If this is an event parameter, whether it's stored in the topics of the log rather than its data.
+    pub indexed: bool,
+}
+
+/// A function of a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AbiFunction {
+    /// The name of the function.
+    pub name: String,
+
+    /// The parameters of the function.
+    pub inputs: Vec<AbiParam>,
+
+    /// The return values of the function.
+    pub outputs: Vec<AbiParam>,
+}
+
+/// A custom error of a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AbiError {
+    /// The name of the error.
+    pub name: String,
+
+    /// The parameters of the error.
+    pub inputs: Vec<AbiParam>,
+}
+
+/// An event of a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AbiEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The parameters of the event, indexed or not.
+    pub inputs: Vec<AbiParam>,
+
+    /// Whether the event's signature is left out of its topics.
+    pub anonymous: bool,
+}
+
+fn signature(name: &str, params: &[AbiParam]) -> String {
+    let types: Vec<_> = params.iter().map(|it| it.ty.to_string()).collect();
+    format!("{name}({})", types.join(","))
+}
+
+fn selector(name: &str, params: &[AbiParam]) -> [u8; 4] {
+    let mut selector = ContractFunctionSelector::new(name);
+
+    for param in params {
+        selector.add_param_type(&param.ty.to_string());
+    }
+
+    selector.finish()
+}
+
+fn types(params: &[AbiParam]) -> Vec<AbiType> {
+    params.iter().map(|it| it.ty.clone()).collect()
+}
+
+impl AbiFunction {
+    /// Returns the signature of this function, such as `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.inputs)
+    }
+
+    /// Returns the 4 byte selector of this function, which prefixes calls to it.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        selector(&self.name, &self.inputs)
+    }
+
+    /// Encode a call to this function, the selector followed by the ABI encoded `args`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `args` don't match the parameters of this function.
+    pub fn encode_call(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        let mut out = self.selector().to_vec();
+        out.append(&mut AbiValue::encode(&types(&self.inputs), args)?);
+        Ok(out)
+    }
+
+    /// Decode the data returned by a call to this function.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `data` isn't a valid encoding of this function's return values.
+    pub fn decode_output(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        AbiValue::decode(&types(&self.outputs), data)
+    }
+}
+
+impl AbiError {
+    /// Returns the signature of this error, such as `InsufficientBalance(uint256,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.inputs)
+    }
+
+    /// Returns the 4 byte selector of this error, which prefixes the data of reverts with it.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        selector(&self.name, &self.inputs)
+    }
+}
+
+impl AbiEvent {
+    /// Returns the signature of this event, such as `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        signature(&self.name, &self.inputs)
+    }
+
+    /// Returns the keccak256 hash of this event's signature, which is the first topic of its (non-anonymous) logs.
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        Keccak256::digest(self.signature()).into()
+    }
+
+    /// Decode the parameters of a log of this event, in the order they're declared in.
+    ///
+    /// Indexed parameters of reference types (`string`, `bytes`, arrays and tuples, even static ones like `uint256[2]`)
+    /// are only stored as a hash, so they're decoded as a `bytes32` ([`AbiValue::FixedBytes`]).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the log isn't of this event,
+    ///   or if `topics` or `data` aren't valid encodings of this event's parameters.
+    pub fn decode_log<T: AsRef<[u8]>>(
+        &self,
+        topics: &[T],
+        data: &[u8],
+    ) -> crate::Result<Vec<AbiValue>> {
+        let topics = match self.anonymous {
+            true => topics,
+            false => match topics.split_first() {
+                Some((first, rest)) if first.as_ref() == self.topic() => rest,
+                _ => return Err(Error::abi(format!("log isn't a `{}` event", self.signature()))),
+            },
+        };
+
+        let indexed_count = self.inputs.iter().filter(|it| it.indexed).count();
+
+        if topics.len() != indexed_count {
+            return Err(Error::abi(format!(
+                "expected {indexed_count} indexed topics for `{}`, found {}",
+                self.signature(),
+                topics.len()
+            )));
+        }
+
+        let unindexed: Vec<_> =
+            self.inputs.iter().filter(|it| !it.indexed).map(|it| it.ty.clone()).collect();
+
+        let mut unindexed = AbiValue::decode(&unindexed, data)?.into_iter();
+        let mut topics = topics.iter();
+
+        self.inputs
+            .iter()
+            .map(|param| {
+                if !param.indexed {
+                    return Ok(unindexed.next().expect("decoded one value per unindexed param"));
+                }
+
+                let topic = topics.next().expect("checked the number of topics above").as_ref();
+
+                if matches!(
+                    param.ty,
+                    AbiType::Bytes
+                        | AbiType::String
+                        | AbiType::Array(_)
+                        | AbiType::FixedArray(..)
+                        | AbiType::Tuple(_)
+                ) {
+                    return Ok(AbiValue::FixedBytes(topic.to_vec()));
+                }
+
+                if topic.len() != 32 {
+                    return Err(Error::abi("topics must be 32 bytes long"));
+                }
+
+                AbiValue::decode_word(&param.ty, topic)
+            })
+            .collect()
+    }
+}
+
+/// A contract's ABI (Application Binary Interface), the functions, errors, and events it has and their types.
+///
+/// Loaded from the ABI JSON the Solidity compiler outputs,
+/// so that calls can be encoded and their results decoded by name.
+///
+/// # Examples
+/// ```
+/// use hedera::{AbiValue, ContractAbi};
+///
+/// let abi = ContractAbi::from_json(r#"[{
+///     "type": "function",
+///     "name": "balanceOf",
+///     "inputs": [{ "name": "owner", "type": "address" }],
+///     "outputs": [{ "name": "", "type": "uint256" }],
+///     "stateMutability": "view"
+/// }]"#)?;
+///
+/// let owner = "0x00000000000000000000000000000000000003e9".parse()?;
+/// let call = abi.encode_call("balanceOf", &[AbiValue::Address(owner)])?;
+///
+/// assert_eq!(call[..4], [0x70, 0xa0, 0x82, 0x31]);
+/// # Ok::<(), hedera::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractAbi {
+    constructor: Option<Vec<AbiParam>>,
+    functions: Vec<AbiFunction>,
+    errors: Vec<AbiError>,
+    events: Vec<AbiEvent>,
+}
+
+impl ContractAbi {
+    /// Parse a `ContractAbi` from the ABI JSON the Solidity compiler outputs.
+    ///
+    /// Either the ABI itself (an array), or a compiler artifact with an `abi` field (such as Hardhat's or Foundry's) is accepted.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid ABI, or uses a type that isn't supported.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let entries = match serde_json::from_str(json).map_err(Error::basic_parse)? {
+            AbiJson::Abi(entries) | AbiJson::Artifact { abi: entries } => entries,
+        };
+
+        let mut abi = Self::default();
+
+        for entry in entries {
+            let inputs = params(entry.inputs)?;
+
+            match entry.kind.as_str() {
+                "constructor" => abi.constructor = Some(inputs),
+                "function" => abi.functions.push(AbiFunction {
+                    name: entry.name,
+                    inputs,
+                    outputs: params(entry.outputs)?,
+                }),
+                "error" => abi.errors.push(AbiError { name: entry.name, inputs }),
+                "event" => abi.events.push(AbiEvent {
+                    name: entry.name,
+                    inputs,
+                    anonymous: entry.anonymous,
+                }),
+                // `fallback` and `receive` can't be called by name.
+                _ => {}
+            }
+        }
+
+        Ok(abi)
+    }
+
+    /// Returns the functions of this contract.
+    #[must_use]
+    pub fn functions(&self) -> &[AbiFunction] {
+        &self.functions
+    }
+
+    /// Returns the custom errors of this contract.
+    #[must_use]
+    pub fn errors(&self) -> &[AbiError] {
+        &self.errors
+    }
+
+    /// Returns the events of this contract.
+    #[must_use]
+    pub fn events(&self) -> &[AbiEvent] {
+        &self.events
+    }
+
+    /// Returns the function with the given name, or signature (such as `transfer(address,uint256)`).
+    ///
+    /// If the function is overloaded, the first overload is returned, use its signature to pick a different one.
+    #[must_use]
+    pub fn function(&self, name: &str) -> Option<&AbiFunction> {
+        match name.contains('(') {
+            true => self.functions.iter().find(|it| it.signature() == name),
+            false => self.functions.iter().find(|it| it.name == name),
+        }
+    }
+
+    /// Returns the custom error with the given name, or signature.
+    #[must_use]
+    pub fn error(&self, name: &str) -> Option<&AbiError> {
+        match name.contains('(') {
+            true => self.errors.iter().find(|it| it.signature() == name),
+            false => self.errors.iter().find(|it| it.name == name),
+        }
+    }
+
+    /// Returns the event with the given name, or signature.
+    #[must_use]
+    pub fn event(&self, name: &str) -> Option<&AbiEvent> {
+        match name.contains('(') {
+            true => self.events.iter().find(|it| it.signature() == name),
+            false => self.events.iter().find(|it| it.name == name),
+        }
+    }
+
+    fn function_or_err(&self, name: &str) -> crate::Result<&AbiFunction> {
+        self.function(name).ok_or_else(|| Error::abi(format!("no function named `{name}`")))
+    }
+
+    /// Encode a call to the function `name` (or signature) with `args`, for instance
+    /// for [`ContractExecuteTransaction::function_parameters`](crate::ContractExecuteTransaction::function_parameters).
+    ///
+    /// If the function is overloaded, the overload whose parameters `args` match is used.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such function, or if `args` don't match its parameters.
+    pub fn encode_call(&self, name: &str, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        if name.contains('(') {
+            return self.function_or_err(name)?.encode_call(args);
+        }
+
+        let mut overloads = self.functions.iter().filter(|it| it.name == name);
+
+        let first =
+            overloads.next().ok_or_else(|| Error::abi(format!("no function named `{name}`")))?;
+
+        let result = first.encode_call(args);
+
+        // only try the other overloads if the first one didn't fit.
+        match result {
+            Ok(_) => result,
+            Err(_) => overloads.find_map(|it| it.encode_call(args).ok()).map_or(result, Ok),
+        }
+    }
+
+    /// Encode the arguments of this contract's constructor, which are appended to its bytecode when it's created,
+    /// for instance with [`ContractCreateFlow::constructor_parameters`](crate::ContractCreateFlow::constructor_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `args` don't match the constructor's parameters.
+    pub fn encode_constructor(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        AbiValue::encode(&types(self.constructor.as_deref().unwrap_or_default()), args)
+    }
+
+    /// Decode the data returned by a call to the function `name` (or signature),
+    /// such as [`ContractFunctionResult::bytes`](crate::ContractFunctionResult::bytes).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such function, or if `data` isn't a valid encoding of its return values.
+    pub fn decode_output(&self, name: &str, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        self.function_or_err(name)?.decode_output(data)
+    }
+
+    /// Decode the data of a revert with one of this contract's custom errors, matched by its selector.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the selector doesn't match any custom error,
+    ///   or if the rest of `data` isn't a valid encoding of the error's parameters.
+    pub fn decode_error(&self, data: &[u8]) -> crate::Result<(&AbiError, Vec<AbiValue>)> {
+        let error = data
+            .get(..4)
+            .and_then(|selector| self.errors.iter().find(|it| it.selector() == selector))
+            .ok_or_else(|| Error::abi("revert data doesn't match any custom error"))?;
+
+        Ok((error, AbiValue::decode(&types(&error.inputs), &data[4..])?))
+    }
+
+    /// Decode a log of one of this contract's events, matched by its first topic.
+    ///
+    /// See [`AbiEvent::decode_log`].
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the log doesn't match any (non-anonymous) event,
+    ///   or if `topics` or `data` aren't valid encodings of the event's parameters.
+    pub fn decode_event<T: AsRef<[u8]>>(
+        &self,
+        topics: &[T],
+        data: &[u8],
+    ) -> crate::Result<(&AbiEvent, Vec<AbiValue>)> {
+        let event = topics
+            .first()
+            .and_then(|topic| {
+                self.events.iter().find(|it| !it.anonymous && it.topic() == topic.as_ref())
+            })
+            .ok_or_else(|| Error::abi("log doesn't match any event"))?;
+
+        Ok((event, event.decode_log(topics, data)?))
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+#[serde(untagged)]
+enum AbiJson {
+    Abi(Vec<EntryJson>),
+    Artifact { abi: Vec<EntryJson> },
+}
+
+#[derive(serde_derive::Deserialize)]
+struct EntryJson {
+    #[serde(rename = "type", default = "default_kind")]
+    kind: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    inputs: Vec<ParamJson>,
+    #[serde(default)]
+    outputs: Vec<ParamJson>,
+    #[serde(default)]
+    anonymous: bool,
+}
+
+// `type` used to be optional for functions.
+fn default_kind() -> String {
+    "function".to_owned()
+}
+
+#[derive(serde_derive::Deserialize)]
+struct ParamJson {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    indexed: bool,
+    /// The fields of a `tuple` (or `tuple[]`, and so on).
+    #[serde(default)]
+    components: Vec<ParamJson>,
+}
+
+impl ParamJson {
+    /// The canonical type name of this parameter, with `tuple` replaced by the types of its components.
+    fn type_name(&self) -> String {
+        match self.ty.strip_prefix("tuple") {
+            Some(suffix) => {
+                let components: Vec<_> = self.components.iter().map(Self::type_name).collect();
+                format!("({}){suffix}", components.join(","))
+            }
+            None => self.ty.clone(),
+        }
+    }
+}
+
+fn params(params: Vec<ParamJson>) -> crate::Result<Vec<AbiParam>> {
+    params
+        .into_iter()
+        .map(|it| {
+            let ty = it.type_name().parse()?;
+            Ok(AbiParam { name: it.name, ty, indexed: it.indexed })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::ContractAbi;
+    use crate::{
+        AbiValue,
+        EvmAddress,
+    };
+
+    const ERC20: &str = r#"[
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "amount", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "name",
+            "inputs": [],
+            "outputs": [{ "name": "", "type": "string" }],
+            "stateMutability": "view"
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "error",
+            "name": "ERC20InsufficientBalance",
+            "inputs": [
+                { "name": "sender", "type": "address" },
+                { "name": "balance", "type": "uint256" },
+                { "name": "needed", "type": "uint256" }
+            ]
+        }
+    ]"#;
+
+    const ALICE: EvmAddress = EvmAddress(hex!("00000000000000000000000000000000000003e9"));
+    const BOB: EvmAddress = EvmAddress(hex!("00000000000000000000000000000000000003ea"));
+
+    #[test]
+    fn encode_call() {
+        let abi = ContractAbi::from_json(ERC20).unwrap();
+
+        let transfer = abi.function("transfer").unwrap();
+        assert_eq!(transfer.signature(), "transfer(address,uint256)");
+        assert_eq!(transfer.selector(), hex!("a9059cbb"));
+
+        let call = abi.encode_call("transfer", &[BOB.into(), 10_u32.into()]).unwrap();
+
+        assert_eq!(
+            call,
+            hex!(
+                "a9059cbb"
+                "00000000000000000000000000000000000000000000000000000000000003ea"
+                "000000000000000000000000000000000000000000000000000000000000000a"
+            )
+        );
+
+        assert!(abi.encode_call("transfer", &[BOB.into()]).is_err());
+        assert!(abi.encode_call("transfer", &[10_u32.into(), BOB.into()]).is_err());
+        assert!(abi.encode_call("approve", &[]).is_err());
+    }
+
+    #[test]
+    fn structs() {
+        let abi = ContractAbi::from_json(
+            r#"[{
+                "type": "function",
+                "name": "submit",
+                "inputs": [{
+                    "name": "orders",
+                    "type": "tuple[]",
+                    "internalType": "struct Exchange.Order[]",
+                    "components": [
+                        { "name": "maker", "type": "address" },
+                        {
+                            "name": "amounts",
+                            "type": "tuple",
+                            "components": [
+                                { "name": "sell", "type": "uint256" },
+                                { "name": "buy", "type": "uint256" }
+                            ]
+                        }
+                    ]
+                }],
+                "outputs": []
+            }]"#,
+        )
+        .unwrap();
+
+        let submit = abi.function("submit").unwrap();
+
+        assert_eq!(submit.signature(), "submit((address,(uint256,uint256))[])");
+        assert_eq!(submit.selector(), hex!("86c807d5"));
+
+        let order = AbiValue::Tuple(Vec::from([
+            ALICE.into(),
+            AbiValue::Tuple(Vec::from([1_u8.into(), 2_u8.into()])),
+        ]));
+
+        let call = abi.encode_call("submit", &[AbiValue::Array(Vec::from([order]))]).unwrap();
+
+        assert_eq!(
+            call[4..],
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "00000000000000000000000000000000000000000000000000000000000003e9"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+            )
+        );
+    }
+
+    #[test]
+    fn decode_output() {
+        let abi = ContractAbi::from_json(ERC20).unwrap();
+
+        let output = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000005"
+            "546f6b656e000000000000000000000000000000000000000000000000000000"
+        );
+
+        assert_eq!(abi.decode_output("name", &output).unwrap(), [AbiValue::from("Token")]);
+    }
+
+    #[test]
+    fn decode_error() {
+        let abi = ContractAbi::from_json(ERC20).unwrap();
+
+        let data = hex!(
+            "e450d38c"
+            "00000000000000000000000000000000000000000000000000000000000003e9"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+            "000000000000000000000000000000000000000000000000000000000000000a"
+        );
+
+        let (error, values) = abi.decode_error(&data).unwrap();
+
+        assert_eq!(error.name, "ERC20InsufficientBalance");
+        assert_eq!(values, [AbiValue::from(ALICE), 3_u32.into(), 10_u32.into()]);
+    }
+
+    #[test]
+    fn decode_event() {
+        let abi = ContractAbi::from_json(ERC20).unwrap();
+
+        let transfer = abi.event("Transfer").unwrap();
+        assert_eq!(
+            transfer.topic(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+
+        let topics = [
+            transfer.topic().to_vec(),
+            hex!("00000000000000000000000000000000000000000000000000000000000003e9").to_vec(),
+            hex!("00000000000000000000000000000000000000000000000000000000000003ea").to_vec(),
+        ];
+        let data = hex!("000000000000000000000000000000000000000000000000000000000000000a");
+
+        let (event, values) = abi.decode_event(&topics, &data).unwrap();
+
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(values, [AbiValue::from(ALICE), BOB.into(), 10_u32.into()]);
+
+        // missing the indexed `to`.
+        assert!(abi.decode_event(&topics[..2], &data).is_err());
+    }
+
+    #[test]
+    fn decode_event_indexed_reference_types() {
+        let abi = ContractAbi::from_json(
+            r#"[{
+                "type": "event",
+                "name": "Stored",
+                "inputs": [
+                    { "name": "pair", "type": "uint256[2]", "indexed": true },
+                    { "name": "point", "type": "tuple", "indexed": true, "components": [
+                        { "name": "x", "type": "uint256" },
+                        { "name": "y", "type": "uint256" }
+                    ] }
+                ],
+                "anonymous": false
+            }]"#,
+        )
+        .unwrap();
+
+        let stored = abi.event("Stored").unwrap();
+
+        let pair_hash = hex!("1111111111111111111111111111111111111111111111111111111111111111");
+        let point_hash = hex!("2222222222222222222222222222222222222222222222222222222222222222");
+        let topics = [stored.topic().to_vec(), pair_hash.to_vec(), point_hash.to_vec()];
+
+        let values = stored.decode_log(&topics, &[]).unwrap();
+
+        assert_eq!(
+            values,
+            [AbiValue::FixedBytes(pair_hash.to_vec()), AbiValue::FixedBytes(point_hash.to_vec())]
+        );
+    }
+}
@@ -28,6 +28,8 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+#[cfg(feature = "serde")]
+use crate::ContractAbi;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -62,6 +64,10 @@ pub struct ContractCallQueryData {
 
     /// The sender for this transaction.
     sender_account_id: Option<AccountId>,
+
+    /// The ABI used to decode the custom errors of a reverted call.
+    #[cfg(feature = "serde")]
+    contract_abi: Option<ContractAbi>,
 }
 
 impl ContractCallQuery {
@@ -126,6 +132,22 @@ impl ContractCallQuery {
         self.data.sender_account_id = Some(sender_account_id);
         self
     }
+
+    /// Gets the ABI used to decode the custom errors of a reverted call.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn get_contract_abi(&self) -> Option<&ContractAbi> {
+        self.data.contract_abi.as_ref()
+    }
+
+    /// Sets the ABI used to decode the custom errors of a reverted call.
+    ///
+    /// Without it, a custom error is returned as [`ContractRevert::Unknown`](crate::ContractRevert::Unknown).
+    #[cfg(feature = "serde")]
+    pub fn contract_abi(&mut self, abi: ContractAbi) -> &mut Self {
+        self.data.contract_abi = Some(abi);
+        self
+    }
 }
 
 impl From<ContractCallQueryData> for AnyQueryData {
@@ -159,6 +181,11 @@ impl ToQueryProtobuf for ContractCallQueryData {
 impl QueryExecute for ContractCallQueryData {
     type Response = ContractFunctionResult;
 
+    #[cfg(feature = "serde")]
+    fn contract_abi(&self) -> Option<&ContractAbi> {
+        self.contract_abi.as_ref()
+    }
+
     fn execute(
         &self,
         channel: Channel,
@@ -53,6 +53,11 @@ use crate::{
 /// There is a fee in hbars to maintain that storage until the expiration time,
 /// and that fee is added as part of the transaction fee.
 ///
+/// A reverted call fails [`get_receipt`](crate::TransactionResponse::get_receipt) with
+/// [`Error::ReceiptStatus`](crate::Error::ReceiptStatus), which doesn't include the revert reason,
+/// [`get_record`](crate::TransactionResponse::get_record) fails with
+/// [`Error::ContractRevert`](crate::Error::ContractRevert) instead, which does.
+///
 pub type ContractExecuteTransaction = Transaction<ContractExecuteTransactionData>;
 
 #[derive(Default, Debug, Clone)]
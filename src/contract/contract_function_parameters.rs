@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::max;
 use std::str::FromStr;
 
@@ -10,6 +11,10 @@ use num_bigint::{
 use self::private::Sealed;
 use crate::contract::contract_function_selector::ContractFunctionSelector;
 use crate::ethereum::SolidityAddress;
+use crate::{
+    AbiType,
+    AbiValue,
+};
 
 /// Builder for encoding parameters for a Solidity contract constructor/function call.
 #[derive(Debug, Clone, Default)]
@@ -19,7 +24,7 @@ pub struct ContractFunctionParameters {
 
 #[derive(Debug, Clone)]
 struct Argument {
-    type_name: &'static str,
+    type_name: Cow<'static, str>,
     value_bytes: Vec<u8>,
     is_dynamic: bool,
 }
@@ -133,13 +138,18 @@ impl ContractFunctionParameters {
     // since downstream code can just...
     // Call this with `Option<&A>` anyway if they want to keep ownership of it.
     pub fn to_bytes(&self, func_name: Option<&str>) -> Vec<u8> {
-        let mut current_dynamic_offset = self.args.len() * 32;
+        // static tuples and fixed size arrays take up more than one slot in the head.
+        let mut current_dynamic_offset = self
+            .args
+            .iter()
+            .map(|arg| if arg.is_dynamic { 32 } else { arg.value_bytes.len() })
+            .sum::<usize>();
         let mut arg_bytes = Vec::new();
         let mut dynamic_arg_bytes = Vec::new();
         let mut function_selector = func_name.map(ContractFunctionSelector::new);
         for arg in &self.args {
             if let Some(selector) = &mut function_selector {
-                selector.add_param_type(arg.type_name);
+                selector.add_param_type(&arg.type_name);
             }
             if arg.is_dynamic {
                 arg_bytes.extend_from_slice(
@@ -167,7 +177,7 @@ impl ContractFunctionParameters {
     /// Add a `string` argument to the `ContractFunctionParameters`
     pub fn add_string<T: AsRef<str>>(&mut self, val: T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string",
+            type_name: "string".into(),
             value_bytes: encode_dynamic_bytes(val.as_ref().as_bytes()),
             is_dynamic: true,
         });
@@ -177,7 +187,7 @@ impl ContractFunctionParameters {
     /// Add a `string[]` argument to the `ContractFunctionParameters`
     pub fn add_string_array<T: AsRef<str>>(&mut self, val: &[T]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string[]",
+            type_name: "string[]".into(),
             value_bytes: encode_array_of_dynamic_byte_arrays(
                 val.iter().map(|s| s.as_ref().as_bytes()),
                 val.len(),
@@ -190,7 +200,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes` argument to the `ContractFunctionParameters`
     pub fn add_bytes(&mut self, val: &[u8]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes",
+            type_name: "bytes".into(),
             value_bytes: encode_dynamic_bytes(val),
             is_dynamic: true,
         });
@@ -200,7 +210,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes_array(&mut self, val: &[&[u8]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes[]",
+            type_name: "bytes[]".into(),
             value_bytes: encode_array_of_dynamic_byte_arrays(val, val.len()),
             is_dynamic: true,
         });
@@ -210,7 +220,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32` argument to the `ContractFunctionParameters`
     pub fn add_bytes32<T: AsBytes32 + ?Sized>(&mut self, val: &T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: "bytes32".into(),
             value_bytes: encode_array_of_32_byte(val),
             is_dynamic: false,
         });
@@ -220,7 +230,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes32_array(&mut self, val: &[[u8; 32]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: "bytes32".into(),
             value_bytes: encode_array_of_32_byte_elements(val.iter().copied(), val.len()),
             is_dynamic: true,
         });
@@ -230,7 +240,7 @@ impl ContractFunctionParameters {
     /// Add a `bool` argument to the `ContractFunctionParameters`
     pub fn add_bool(&mut self, val: bool) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bool",
+            type_name: "bool".into(),
             value_bytes: left_pad_32_bytes(
                 // a bool in rust is guaranteed to be of value 0 or 1
                 u32::from(val).to_be_bytes().as_slice(),
@@ -247,7 +257,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: type_name.into(),
             value_bytes: truncate_and_left_pad_32_bytes(val, byte_count).to_vec(),
             is_dynamic: false,
         });
@@ -264,7 +274,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: type_name.into(),
             value_bytes: encode_array_of_32_byte_elements(
                 values.iter().map(|val| truncate_and_left_pad_32_bytes(val, byte_count)),
                 values.len(),
@@ -917,7 +927,7 @@ impl ContractFunctionParameters {
     /// Add an `address` argument to the `ContractFunctionParameters`
     pub fn add_address(&mut self, address: &str) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address",
+            type_name: "address".into(),
             value_bytes: encode_address(address).to_vec(),
             is_dynamic: false,
         });
@@ -927,7 +937,7 @@ impl ContractFunctionParameters {
     /// Add an `address[]` argument to the `ContractFunctionParameters`
     pub fn add_address_array(&mut self, addresses: &[&str]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address[]",
+            type_name: "address[]".into(),
             value_bytes: encode_array_of_32_byte_elements(
                 addresses.iter().map(|addr| encode_address(addr)),
                 addresses.len(),
@@ -951,12 +961,26 @@ impl ContractFunctionParameters {
         right_pad_32_bytes(&mut value_bytes);
 
         self.args.push(Argument {
-            type_name: "function",
+            type_name: "function".into(),
             value_bytes: value_bytes,
             is_dynamic: false,
         });
         self
     }
+
+    /// Add an argument of any ABI type to the `ContractFunctionParameters`,
+    /// such as a struct (tuple), a fixed size array, or a nested array.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if `value` doesn't fit `ty`.
+    pub fn add_value(&mut self, ty: &AbiType, value: &AbiValue) -> crate::Result<&mut Self> {
+        self.args.push(Argument {
+            type_name: ty.to_string().into(),
+            value_bytes: AbiValue::encode_single(ty, value)?,
+            is_dynamic: ty.is_dynamic(),
+        });
+        Ok(self)
+    }
 }
 
 fn left_pad_32_bytes(bytes: &[u8], is_negative: bool) -> [u8; 32] {
@@ -1044,6 +1068,7 @@ fn encode_array_of_32_byte<T: AsBytes32 + ?Sized>(elements: &T) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
+    use hex_literal::hex;
     use num_bigint::{
         BigInt,
         BigUint,
@@ -1051,6 +1076,11 @@ mod tests {
 
     use crate::contract::contract_function_parameters::ContractFunctionParameters;
     use crate::contract::contract_function_selector::ContractFunctionSelector;
+    use crate::{
+        AbiType,
+        AbiValue,
+        EvmAddress,
+    };
 
     #[test]
     fn misc_params() {
@@ -1176,6 +1206,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tuple_params() {
+        let order: AbiType = "(address,uint64)".parse().unwrap();
+        let order_value = AbiValue::Tuple(Vec::from([
+            EvmAddress::from(hex!("1122334455667788990011223344556677889900")).into(),
+            7_u64.into(),
+        ]));
+
+        let param_bytes = ContractFunctionParameters::new()
+            .add_value(&order, &order_value)
+            .unwrap()
+            .add_string("hi")
+            .to_bytes(Some("foo"));
+
+        let mut selector = ContractFunctionSelector::new("foo");
+        selector.add_param_type("(address,uint64)").add_string();
+
+        assert_eq!(param_bytes[..4], selector.finish());
+
+        assert_eq!(
+            hex::encode(&param_bytes[4..]),
+            "0000000000000000000000001122334455667788990011223344556677889900\
+                0000000000000000000000000000000000000000000000000000000000000007\
+                0000000000000000000000000000000000000000000000000000000000000060\
+                0000000000000000000000000000000000000000000000000000000000000002\
+                6869000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        assert!(ContractFunctionParameters::new().add_value(&order, &7_u64.into()).is_err());
+    }
+
     // regression test for https://github.com/hashgraph/hedera-sdk-rust/issues/715
     #[test]
     fn long_string() {
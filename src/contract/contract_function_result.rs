@@ -27,12 +27,21 @@ use num_bigint::{
     BigUint,
 };
 
+use crate::contract::contract_log_info;
 use crate::protobuf::ToProtobuf;
+#[cfg(feature = "serde")]
 use crate::{
+    AbiEvent,
+    Error,
+};
+use crate::{
+    AbiType,
+    AbiValue,
     AccountId,
     ContractId,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevert,
     FromProtobuf,
 };
 
@@ -109,6 +118,14 @@ impl ContractFunctionResult {
         Some((offset, len))
     }
 
+    /// Returns why this call reverted, decoded from [`error_message`](Self::error_message).
+    ///
+    /// Returns `None` if the call didn't revert, or if it failed without revert data.
+    #[must_use]
+    pub fn revert(&self) -> Option<ContractRevert> {
+        self.error_message.as_deref().and_then(ContractRevert::from_error_message)
+    }
+
     /// Get the whole raw function result.
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
@@ -223,6 +240,85 @@ impl ContractFunctionResult {
     pub fn get_i256(&self, index: usize) -> Option<BigInt> {
         self.get_bytes32(index).map(|it| BigInt::from_signed_bytes_be(it))
     }
+
+    /// Get the value at `index` as any solidity type, such as a struct (tuple) or a nested array.
+    ///
+    /// Like the other getters, `index` is a slot (a multiple of 32 bytes), so static structs and
+    /// fixed size arrays before it count as more than one. Use [`get_values`](Self::get_values) to decode everything at once.
+    #[must_use]
+    pub fn get_value(&self, index: usize, ty: &AbiType) -> Option<AbiValue> {
+        AbiValue::decode_at(ty, &self.bytes, index * Self::SLOT_SIZE).ok()
+    }
+
+    /// Decode the whole function result as values of `types`, the function's return types.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if the result isn't a valid encoding of `types`.
+    pub fn get_values(&self, types: &[AbiType]) -> crate::Result<Vec<AbiValue>> {
+        AbiValue::decode(types, &self.bytes)
+    }
+
+    /// Returns false if the bloom filter of this call rules out that any of its logs has `item` as a topic (or as its contract's address).
+    ///
+    /// A bloom filter can have false positives, so `true` only means some log _might_ have it.
+    #[must_use]
+    pub fn bloom_may_contain(&self, item: &[u8]) -> bool {
+        contract_log_info::bloom_may_contain(&self.bloom, item)
+    }
+
+    /// Returns the logs of this call whose first topic is `topic`, such as the signature hash of an event.
+    ///
+    /// The bloom filters of the call and of each log are checked first, so that logs without the topic are skipped cheaply.
+    pub fn logs_with_topic0<'a>(
+        &'a self,
+        topic: &'a [u8],
+    ) -> impl Iterator<Item = &'a ContractLogInfo> + 'a {
+        let logs = match self.bloom_may_contain(topic) {
+            true => self.logs.as_slice(),
+            false => &[],
+        };
+
+        logs.iter().filter(move |it| it.has_topic0(topic))
+    }
+
+    /// Decode every log of `event` this call produced, in order.
+    ///
+    /// This works the same for the result of a [`ContractCallQuery`](crate::ContractCallQuery),
+    /// and for the [`contract_function_result`](crate::TransactionRecord::contract_function_result) of a transaction record.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # fn example(record: hedera::TransactionRecord, erc20_abi: &str) -> hedera::Result<()> {
+    /// use hedera::ContractAbi;
+    ///
+    /// let abi = ContractAbi::from_json(erc20_abi)?;
+    /// let transfer = abi.event("Transfer").unwrap();
+    ///
+    /// if let Some(result) = &record.contract_function_result {
+    ///     for values in result.decode_events(transfer)? {
+    ///         println!("{} sent {} to {}", values[0], values[2], values[1]);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if `event` is anonymous, since its logs can't be told apart by their topics.
+    /// - [`Error::Abi`](crate::Error::Abi) if a log of `event` doesn't match its parameters.
+    #[cfg(feature = "serde")]
+    pub fn decode_events(&self, event: &AbiEvent) -> crate::Result<Vec<Vec<AbiValue>>> {
+        if event.anonymous {
+            return Err(Error::abi(format!(
+                "`{}` is anonymous, so its logs can't be found by topic",
+                event.signature()
+            )));
+        }
+
+        let topic = event.topic();
+
+        self.logs_with_topic0(&topic).map(|it| it.decode_event(event)).collect()
+    }
 }
 
 impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
@@ -328,9 +424,12 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        AbiType,
+        AbiValue,
         AccountId,
         ContractFunctionResult,
         ContractId,
+        ContractLogInfo,
         ContractNonceInfo,
     };
 
@@ -472,6 +571,70 @@ mod tests {
         )
     }
 
+    #[test]
+    fn struct_results() {
+        // returns `((string,uint8)[],bool)`
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: hex!(
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000009"
+                "0000000000000000000000000000000000000000000000000000000000000005"
+                "616c696365000000000000000000000000000000000000000000000000000000"
+            )
+            .to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        let people: AbiType = "(string,uint8)[]".parse().unwrap();
+        let expected =
+            AbiValue::Array(Vec::from([AbiValue::Tuple(Vec::from(["alice".into(), 9_u8.into()]))]));
+
+        assert_eq!(result.get_value(0, &people).unwrap(), expected);
+        assert_eq!(result.get_bool(1), Some(true));
+        assert_eq!(
+            result.get_values(&[people, AbiType::Bool]).unwrap(),
+            [expected, AbiValue::Bool(true)]
+        );
+    }
+
+    #[test]
+    fn logs_with_topic0() {
+        const TOPIC: [u8; 32] =
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+        let log = |topic: [u8; 32]| ContractLogInfo {
+            contract_id: ContractId::from(3),
+            bloom: Vec::new(),
+            topics: Vec::from([topic.to_vec()]),
+            data: Vec::new(),
+        };
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            log_info: [log(TOPIC), log([1; 32]), log(TOPIC)]
+                .iter()
+                .map(ToProtobuf::to_protobuf)
+                .collect(),
+            ..Default::default()
+        };
+
+        let mut result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        assert_eq!(result.logs_with_topic0(&TOPIC).count(), 2);
+
+        // a bloom filter with no bits set, for the whole call, rules out every log.
+        result.bloom = [0; 256].to_vec();
+
+        assert_eq!(result.logs_with_topic0(&TOPIC).count(), 0);
+    }
+
     #[test]
     fn str_array_results() {
         let result = services::ContractFunctionResult {
@@ -1,10 +1,20 @@
 use hedera_proto::services;
+use sha3::{
+    Digest,
+    Keccak256,
+};
 
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
 use crate::ContractId;
+#[cfg(feature = "serde")]
+use crate::{
+    AbiEvent,
+    AbiValue,
+    ContractAbi,
+};
 
 /// The log information for an event returned by a smart contract function call.
 /// One function call may return several such events.
@@ -38,6 +48,57 @@ impl ContractLogInfo {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns false if this log's bloom filter rules out that it has `item` as a topic (or as its contract's address).
+    ///
+    /// A bloom filter can have false positives, so `true` only means this log _might_ have it.
+    #[must_use]
+    pub fn bloom_may_contain(&self, item: &[u8]) -> bool {
+        bloom_may_contain(&self.bloom, item)
+    }
+
+    /// Returns true if this log's first topic is `topic`, such as the signature hash of an event.
+    ///
+    /// Checks the bloom filter first, so that most logs without the topic are skipped cheaply.
+    #[must_use]
+    pub fn has_topic0(&self, topic: &[u8]) -> bool {
+        self.bloom_may_contain(topic) && self.topics.first().is_some_and(|it| it == topic)
+    }
+
+    /// Decode the parameters of this log as `event`, in the order they're declared in.
+    ///
+    /// See [`AbiEvent::decode_log`].
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if this log isn't of `event`, or doesn't match its parameters.
+    #[cfg(feature = "serde")]
+    pub fn decode_event(&self, event: &AbiEvent) -> crate::Result<Vec<AbiValue>> {
+        event.decode_log(&self.topics, &self.data)
+    }
+
+    /// Decode this log as whichever of `abi`'s events it is, matched by its first topic.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if this log doesn't match any of `abi`'s events, or its parameters.
+    #[cfg(feature = "serde")]
+    pub fn decode<'a>(&self, abi: &'a ContractAbi) -> crate::Result<(&'a AbiEvent, Vec<AbiValue>)> {
+        abi.decode_event(&self.topics, &self.data)
+    }
+}
+
+/// Checks the 2048 bit bloom filter of a log (or of all the logs of a call) for `item`, the same way Ethereum does.
+pub(crate) fn bloom_may_contain(bloom: &[u8], item: &[u8]) -> bool {
+    // an empty (or malformed) bloom can't rule anything out.
+    let Ok(bloom) = <&[u8; 256]>::try_from(bloom) else {
+        return true;
+    };
+
+    let hash = Keccak256::digest(item);
+
+    hash[..6].chunks_exact(2).all(|pair| {
+        let bit = usize::from(u16::from_be_bytes([pair[0], pair[1]]) & 2047);
+        bloom[255 - bit / 8] & (1 << (bit % 8)) != 0
+    })
 }
 
 impl FromProtobuf<services::ContractLoginfo> for ContractLogInfo {
@@ -71,13 +132,17 @@ impl ToProtobuf for ContractLogInfo {
 mod tests {
     use expect_test::expect;
     use hedera_proto::services;
+    use hex_literal::hex;
     use prost::Message;
 
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
     };
-    use crate::ContractLogInfo;
+    use crate::{
+        ContractId,
+        ContractLogInfo,
+    };
 
     fn make_info() -> services::ContractLoginfo {
         services::ContractLoginfo {
@@ -92,6 +157,76 @@ mod tests {
         }
     }
 
+    const TRANSFER_TOPIC: [u8; 32] =
+        hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+    #[test]
+    fn bloom() {
+        // the bits set by keccak256(TRANSFER_TOPIC) = ada389e1fc24...
+        let mut bloom = [0; 256];
+        bloom[75] |= 0x08;
+        bloom[195] |= 0x02;
+        bloom[123] |= 0x10;
+
+        let info = ContractLogInfo {
+            contract_id: ContractId::from(10),
+            bloom: bloom.to_vec(),
+            topics: Vec::from([TRANSFER_TOPIC.to_vec()]),
+            data: Vec::new(),
+        };
+
+        assert!(info.bloom_may_contain(&TRANSFER_TOPIC));
+        assert!(!info.bloom_may_contain(&[0; 32]));
+        assert!(info.has_topic0(&TRANSFER_TOPIC));
+
+        let info = ContractLogInfo { bloom: [0; 256].to_vec(), ..info };
+
+        // the bloom rules it out, even though it's the first topic.
+        assert!(!info.has_topic0(&TRANSFER_TOPIC));
+
+        let info = ContractLogInfo { bloom: Vec::new(), ..info };
+
+        assert!(info.has_topic0(&TRANSFER_TOPIC));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn decode_event() {
+        let abi = crate::ContractAbi::from_json(
+            r#"[{
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [
+                    { "name": "from", "type": "address", "indexed": true },
+                    { "name": "to", "type": "address", "indexed": true },
+                    { "name": "tokenId", "type": "uint256", "indexed": true }
+                ],
+                "anonymous": false
+            }]"#,
+        )
+        .unwrap();
+
+        let info = ContractLogInfo {
+            contract_id: ContractId::from(10),
+            bloom: Vec::new(),
+            topics: Vec::from([
+                TRANSFER_TOPIC.to_vec(),
+                [0; 32].to_vec(),
+                hex!("00000000000000000000000000000000000000000000000000000000000003e9").to_vec(),
+                hex!("0000000000000000000000000000000000000000000000000000000000000007").to_vec(),
+            ]),
+            data: Vec::new(),
+        };
+
+        let (event, values) = info.decode(&abi).unwrap();
+
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(values[1].to_string(), "0x00000000000000000000000000000000000003e9");
+        assert_eq!(values[2], crate::AbiValue::from(7_u8));
+
+        assert_eq!(info.decode_event(event).unwrap(), values);
+    }
+
     #[test]
     fn from_protobuf() {
         expect![[r#"
@@ -0,0 +1,263 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use hedera_proto::services;
+use num_bigint::BigUint;
+
+#[cfg(feature = "serde")]
+use crate::ContractAbi;
+use crate::{
+    AbiType,
+    AbiValue,
+};
+
+/// The selector of `Error(string)`, what `revert("reason")` and `require(condition, "reason")` revert with.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The selector of `Panic(uint256)`, what failed `assert`s and checks added by the compiler revert with.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Why a smart contract call reverted, decoded from its revert data.
+///
+/// `Error(string)` and `Panic(uint256)` are always decoded,
+/// custom errors need the contract's ABI, see [`with_abi`](Self::with_abi).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContractRevert {
+    /// `revert("reason")` or `require(condition, "reason")`, encoded as `Error(string)`.
+    Reason(String),
+
+    /// A failed `assert`, an arithmetic overflow, or another check added by the compiler, encoded as `Panic(uint256)`.
+    ///
+    /// See [`panic_description`](Self::panic_description) for what the code means.
+    Panic(BigUint),
+
+    /// One of the contract's custom errors, such as `error InsufficientBalance(uint256 required, uint256 available)`.
+    CustomError {
+        /// The name of the error.
+        name: String,
+
+        /// The names (which may be empty) and values of the error's parameters.
+        params: Vec<(String, AbiValue)>,
+    },
+
+    /// Revert data that wasn't decoded, such as a custom error without the contract's ABI, or an empty `revert()`.
+    Unknown(Vec<u8>),
+}
+
+impl ContractRevert {
+    /// Decode revert `data`, as either an `Error(string)` or a `Panic(uint256)`.
+    ///
+    /// Anything else (including malformed data) is [`Unknown`](Self::Unknown).
+    #[must_use]
+    pub fn from_bytes(data: &[u8]) -> Self {
+        let decode_single = |ty: AbiType| {
+            AbiValue::decode(&[ty], &data[4..]).ok().and_then(|it| it.into_iter().next())
+        };
+
+        let decoded = match data.get(..4) {
+            Some(selector) if selector == ERROR_SELECTOR => decode_single(AbiType::String)
+                .and_then(|it| it.as_str().map(str::to_owned))
+                .map(Self::Reason),
+            Some(selector) if selector == PANIC_SELECTOR => {
+                decode_single(AbiType::Uint(256)).and_then(|it| it.as_uint()).map(Self::Panic)
+            }
+            _ => None,
+        };
+
+        decoded.unwrap_or_else(|| Self::Unknown(data.to_vec()))
+    }
+
+    /// Decode the revert data of a [`ContractFunctionResult::error_message`](crate::ContractFunctionResult::error_message),
+    /// which is hex encoded (with a `0x` prefix).
+    ///
+    /// Returns `None` if the message isn't hex, such as when the call failed without reverting.
+    #[must_use]
+    pub fn from_error_message(message: &str) -> Option<Self> {
+        let data = hex::decode(message.strip_prefix("0x")?).ok()?;
+        Some(Self::from_bytes(&data))
+    }
+
+    /// Decode an [`Unknown`](Self::Unknown) revert as one of `abi`'s custom errors, matched by its selector.
+    ///
+    /// Returns `self` unchanged if it's already decoded, or if it doesn't match any of `abi`'s errors.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn with_abi(self, abi: &ContractAbi) -> Self {
+        let Self::Unknown(data) = &self else {
+            return self;
+        };
+
+        match abi.decode_error(data) {
+            Ok((error, values)) => Self::CustomError {
+                name: error.name.clone(),
+                params: error.inputs.iter().map(|it| it.name.clone()).zip(values).collect(),
+            },
+            Err(_) => self,
+        }
+    }
+
+    /// Returns what the code of a [`Panic`](Self::Panic) means, as documented by Solidity.
+    #[must_use]
+    pub fn panic_description(&self) -> Option<&'static str> {
+        let Self::Panic(code) = self else {
+            return None;
+        };
+
+        let description = match u64::try_from(code).ok()? {
+            0x00 => "generic compiler panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic underflow or overflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "incorrectly encoded storage byte array",
+            0x31 => "pop on an empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to an uninitialized internal function",
+            _ => return None,
+        };
+
+        Some(description)
+    }
+
+    /// The revert of a `ContractCallLocal` response that failed pre-check, if it has one.
+    pub(crate) fn from_response(response: &services::Response) -> Option<Self> {
+        let Some(services::response::Response::ContractCallLocal(response)) = &response.response
+        else {
+            return None;
+        };
+
+        Self::from_error_message(&response.function_result.as_ref()?.error_message)
+    }
+}
+
+impl fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reason(reason) => write!(f, "Error({reason:?})"),
+            Self::Panic(code) => match self.panic_description() {
+                Some(description) => write!(f, "Panic(0x{code:02x}: {description})"),
+                None => write!(f, "Panic(0x{code:02x})"),
+            },
+            Self::CustomError { name, params } => {
+                write!(f, "{name}(")?;
+                for (index, (name, value)) in params.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    if !name.is_empty() {
+                        write!(f, "{name}=")?;
+                    }
+
+                    write!(f, "{value}")?;
+                }
+                f.write_str(")")
+            }
+            Self::Unknown(data) if data.is_empty() => f.write_str("revert without a reason"),
+            Self::Unknown(data) => write!(f, "unknown revert data 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+
+    use super::ContractRevert;
+
+    #[test]
+    fn reason() {
+        // from the Solidity documentation.
+        let data = hex!(
+            "08c379a0"
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "000000000000000000000000000000000000000000000000000000000000001a"
+            "4e6f7420656e6f7567682045746865722070726f76696465642e000000000000"
+        );
+
+        let revert = ContractRevert::from_bytes(&data);
+
+        assert_eq!(revert, ContractRevert::Reason("Not enough Ether provided.".to_owned()));
+        assert_eq!(revert.to_string(), r#"Error("Not enough Ether provided.")"#);
+
+        assert_eq!(
+            ContractRevert::from_error_message(&format!("0x{}", hex::encode(data))),
+            Some(revert)
+        );
+
+        assert_eq!(ContractRevert::from_error_message("CONTRACT_REVERT_EXECUTED"), None);
+    }
+
+    #[test]
+    fn panic() {
+        let revert = ContractRevert::from_bytes(&hex!(
+            "4e487b71"
+            "0000000000000000000000000000000000000000000000000000000000000011"
+        ));
+
+        assert_eq!(revert, ContractRevert::Panic(BigUint::from(0x11_u8)));
+        assert_eq!(revert.panic_description(), Some("arithmetic underflow or overflow"));
+        assert_eq!(revert.to_string(), "Panic(0x11: arithmetic underflow or overflow)");
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(ContractRevert::from_bytes(&[]).to_string(), "revert without a reason");
+
+        // truncated `Error(string)`.
+        let revert = ContractRevert::from_bytes(&hex!("08c379a0"));
+
+        assert_eq!(revert, ContractRevert::Unknown(hex!("08c379a0").to_vec()));
+        assert_eq!(revert.to_string(), "unknown revert data 0x08c379a0");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn custom_error() {
+        let abi = crate::ContractAbi::from_json(
+            r#"[{
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [
+                    { "name": "required", "type": "uint256" },
+                    { "name": "available", "type": "uint256" }
+                ]
+            }]"#,
+        )
+        .unwrap();
+
+        let mut data = abi.error("InsufficientBalance").unwrap().selector().to_vec();
+        data.extend_from_slice(&hex!(
+            "000000000000000000000000000000000000000000000000000000000000000a"
+            "0000000000000000000000000000000000000000000000000000000000000003"
+        ));
+
+        let revert = ContractRevert::from_bytes(&data);
+        assert!(matches!(revert, ContractRevert::Unknown(_)));
+
+        let revert = revert.with_abi(&abi);
+        assert_eq!(revert.to_string(), "InsufficientBalance(required=10, available=3)");
+    }
+}
@@ -18,6 +18,10 @@
  * ‍
  */
 
+mod abi_type;
+mod abi_value;
+#[cfg(feature = "serde")]
+mod contract_abi;
 mod contract_bytecode_query;
 mod contract_call_query;
 mod contract_create_flow;
@@ -32,9 +36,20 @@ mod contract_info;
 mod contract_info_query;
 mod contract_log_info;
 mod contract_nonce_info;
+mod contract_revert;
 mod contract_update_transaction;
 mod delegate_contract_id;
 
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+#[cfg(feature = "serde")]
+pub use contract_abi::{
+    AbiError,
+    AbiEvent,
+    AbiFunction,
+    AbiParam,
+    ContractAbi,
+};
 pub use contract_bytecode_query::ContractBytecodeQuery;
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
 pub use contract_call_query::ContractCallQuery;
@@ -54,6 +69,7 @@ pub use contract_info_query::ContractInfoQuery;
 pub(crate) use contract_info_query::ContractInfoQueryData;
 pub use contract_log_info::ContractLogInfo;
 pub use contract_nonce_info::ContractNonceInfo;
+pub use contract_revert::ContractRevert;
 pub use contract_update_transaction::ContractUpdateTransaction;
 pub(crate) use contract_update_transaction::ContractUpdateTransactionData;
 pub use delegate_contract_id::DelegateContractId;
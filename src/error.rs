@@ -24,6 +24,7 @@ use std::result::Result as StdResult;
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    ContractRevert,
     Hbar,
     Status,
     TransactionId,
@@ -55,6 +56,18 @@ pub enum Error {
     #[error("freeze failed due to node account IDs being unset")]
     FreezeUnsetNodeAccountIds,
 
+    /// [`Transaction::merge`](crate::Transaction::merge) failed because the transactions aren't the same transaction.
+    #[error("cannot merge signatures of transactions with different bodies")]
+    TransactionMergeMismatch,
+
+    /// [`Transaction::freeze_offline`](crate::Transaction::freeze_offline) failed
+    /// because neither the transaction nor the offline builder provided everything needed.
+    #[error("offline freeze failed due to missing {}", .missing.join(", "))]
+    FreezeOfflineMissing {
+        /// What's missing, such as `"payer account ID"`.
+        missing: Vec<&'static str>,
+    },
+
     /// A transaction failed pre-check.
     ///
     /// The transaction had the ID `transaction_id`.
@@ -142,8 +155,7 @@ pub enum Error {
     ///
     /// Examples of when this can happen (non-exhaustive):
     /// - [`PrivateKey::derive`](fn@crate::PrivateKey::derive) when the `PrivateKey` doesn't have a chain code.
-    /// - [`PrivateKey::derive`](fn@crate::PrivateKey::derive)
-    ///   or [`PrivateKey::legacy_derive`](fn@crate::PrivateKey::legacy_derive) on an `Ecsda` key.
+    /// - [`PrivateKey::legacy_derive`](fn@crate::PrivateKey::legacy_derive) on an `Ecdsa` key.
     #[error("Failed to derive a key: {0}")]
     KeyDerive(#[source] BoxStdError),
 
@@ -152,7 +164,7 @@ pub enum Error {
     /// the `Mnemonic` is provided because invalid `Mnemonics`
     /// can technically still provide valid [`PrivateKeys`](crate::PrivateKey).
     #[cfg(feature = "mnemonic")]
-    #[error("failed to parse a mnemonic: {reason}")]
+    #[error("failed to parse a mnemonic: {reason}{}", .mnemonic.typo_hint(.reason))]
     MnemonicParse {
         /// This error's source.
         #[source]
@@ -203,9 +215,46 @@ pub enum Error {
         transaction_id: Option<Box<TransactionId>>,
     },
 
+    /// A smart contract call reverted, with the (decoded) reason it gave.
+    ///
+    /// Returned instead of [`QueryPaymentPreCheckStatus`](Self::QueryPaymentPreCheckStatus) by a reverting
+    /// [`ContractCallQuery`](crate::ContractCallQuery), and instead of [`ReceiptStatus`](Self::ReceiptStatus)
+    /// by a [`TransactionRecordQuery`](crate::TransactionRecordQuery) for a reverted transaction.
+    #[error("contract call reverted with status `{status:?}`: {revert}")]
+    ContractRevert {
+        /// The status of the call, usually [`ContractRevertExecuted`](Status::ContractRevertExecuted).
+        status: Status,
+
+        /// The ID of the reverted transaction, or of the query's payment transaction.
+        transaction_id: Option<Box<TransactionId>>,
+
+        /// Why the call reverted.
+        ///
+        /// Custom errors are decoded when the query was given a [`ContractAbi`](crate::ContractAbi),
+        /// otherwise they can be decoded with [`ContractRevert::with_abi`](crate::ContractRevert::with_abi).
+        revert: Box<ContractRevert>,
+    },
+
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A [`Signer`](crate::Signer) failed to sign a message.
+    #[error("failed to sign a message: {0}")]
+    Signature(#[source] BoxStdError),
+
+    /// Failed to encode or decode values with the Solidity ABI, see [`AbiValue`](crate::AbiValue).
+    ///
+    /// This includes values that don't match their types, and malformed (or too short) ABI encoded data.
+    #[error("ABI encoding error: {0}")]
+    Abi(#[source] BoxStdError),
+
+    /// Failed to load or save a [`Keystore`](crate::Keystore).
+    ///
+    /// This includes reading or writing the file, invalid keystore JSON, and using the wrong password.
+    #[cfg(feature = "serde")]
+    #[error("keystore error: {0}")]
+    Keystore(#[source] BoxStdError),
 }
 
 impl Error {
@@ -228,6 +277,19 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn signature(error: impl Into<BoxStdError>) -> Self {
+        Self::Signature(error.into())
+    }
+
+    pub(crate) fn abi(error: impl Into<BoxStdError>) -> Self {
+        Self::Abi(error.into())
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn keystore(error: impl Into<BoxStdError>) -> Self {
+        Self::Keystore(error.into())
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -0,0 +1,42 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! [EIP-191](https://eips.ethereum.org/EIPS/eip-191) signed data, what Ethereum keys sign that isn't a transaction.
+
+/// The data signed for a `personal_sign` message (version `0x45`).
+pub(crate) fn personal_message(message: &[u8]) -> Vec<u8> {
+    let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    data.extend_from_slice(message);
+    data
+}
+
+/// The data signed for [EIP-712](https://eips.ethereum.org/EIPS/eip-712) typed structured data (version `0x01`).
+///
+/// `struct_hash` is `None` when the primary type is the domain itself.
+pub(crate) fn structured_data(
+    domain_separator: &[u8; 32],
+    struct_hash: Option<&[u8; 32]>,
+) -> Vec<u8> {
+    let mut data = Vec::with_capacity(66);
+    data.extend_from_slice(&[0x19, 0x01]);
+    data.extend_from_slice(domain_separator);
+    data.extend_from_slice(struct_hash.map_or(&[], |it| it.as_slice()));
+    data
+}
@@ -35,6 +35,9 @@ pub enum EthereumData {
     /// Data for a legacy ethereum transaction.
     Legacy(LegacyEthereumData),
 
+    /// Data for an Eip 2930 (access list) ethereum transaction.
+    Eip2930(Eip2930EthereumData),
+
     /// Data for an Eip 1559 ethereum transaction.
     Eip1559(Eip1559EthereumData),
 }
@@ -43,17 +46,28 @@ impl EthereumData {
     pub(super) fn call_data_mut(&mut self) -> &mut Vec<u8> {
         match self {
             EthereumData::Legacy(it) => &mut it.call_data,
+            EthereumData::Eip2930(it) => &mut it.call_data,
             EthereumData::Eip1559(it) => &mut it.call_data,
         }
     }
 
     pub(crate) fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         match bytes.split_first() {
-            // note: eating the 2 here involves a bit of extra work.
+            // note: eating the type byte here involves a bit of extra work.
+            Some((1, bytes)) => Eip2930EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip2930)
+                .map_err(Error::basic_parse),
+
             Some((2, bytes)) => Eip1559EthereumData::decode_rlp(&Rlp::new(bytes))
                 .map(Self::Eip1559)
                 .map_err(Error::basic_parse),
 
+            // legacy transactions are an rlp list, which always starts with a byte >= `0xc0`,
+            // anything in `0x00..=0x7f` is the type of a typed transaction (EIP-2718).
+            Some((&kind, _)) if kind <= 0x7f => Err(Error::basic_parse(format!(
+                "Unsupported ethereum transaction type `{kind:#04x}`"
+            ))),
+
             Some(_) => Ok(Self::Legacy(LegacyEthereumData::from_bytes(bytes)?)),
             None => Err(Error::basic_parse("Empty ethereum transaction data")),
         }
@@ -64,11 +78,20 @@ impl EthereumData {
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             EthereumData::Legacy(it) => it.to_bytes(),
+            EthereumData::Eip2930(it) => it.to_bytes(),
             EthereumData::Eip1559(it) => it.to_bytes(),
         }
     }
 }
 
+struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
+
+impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
+    }
+}
+
 /// Data for a legacy ethereum transaction.
 #[derive(Clone)]
 #[non_exhaustive]
@@ -167,6 +190,176 @@ impl LegacyEthereumData {
     }
 }
 
+/// An entry of the access list of an [`Eip2930EthereumData`] or [`Eip1559EthereumData`].
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AccessListItem {
+    /// The address that the transaction plans to access.
+    pub address: Vec<u8>,
+
+    /// The storage keys of `address` that the transaction plans to access.
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
+impl fmt::Debug for AccessListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { address, storage_keys } = self;
+        f.debug_struct("AccessListItem")
+            .field("address", &hex::encode(address))
+            .field("storage_keys", &HexList(storage_keys))
+            .finish()
+    }
+}
+
+impl rlp::Encodable for AccessListItem {
+    fn rlp_append(&self, rlp: &mut rlp::RlpStream) {
+        rlp.begin_list(2)
+            .append(&self.address)
+            .append_list::<Vec<u8>, _>(self.storage_keys.as_slice());
+    }
+}
+
+impl rlp::Decodable for AccessListItem {
+    fn decode(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 2 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self { address: rlp.val_at(0)?, storage_keys: rlp.list_at(1)? })
+    }
+}
+
+/// Data for an Eip 2930 ethereum transaction, a legacy transaction with an access list.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip2930EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// Price for 1 gas.
+    pub gas_price: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip2930EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            gas_price,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip2930EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("gas_price", &hex::encode(gas_price))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", access_list)
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip2930EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 11 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            gas_price: rlp.val_at(2)?,
+            gas_limit: rlp.val_at(3)?,
+            to: rlp.val_at(4)?,
+            value: rlp.val_at(5)?,
+            call_data: rlp.val_at(6)?,
+            access_list: rlp.list_at(7)?,
+            recovery_id: rlp.val_at(8)?,
+            r: rlp.val_at(9)?,
+            s: rlp.val_at(10)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let (&first, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+
+        if first != 1 {
+            return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+        }
+
+        Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 11);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(&self.access_list)
+            .append(&self.recovery_id)
+            .append(&self.r)
+            .append(&self.s);
+
+        rlp.out().to_vec()
+    }
+}
+
 /// Data for an Eip 1559 ethereum transaction.
 #[derive(Clone)]
 #[non_exhaustive]
@@ -198,7 +391,7 @@ pub struct Eip1559EthereumData {
     pub call_data: Vec<u8>,
 
     /// Specifies an array of addresses and storage keys that the transaction plans to access.
-    pub access_list: Vec<Vec<u8>>,
+    pub access_list: Vec<AccessListItem>,
 
     /// Recovery parameter used to ease the signature verification.
     pub recovery_id: Vec<u8>,
@@ -213,14 +406,6 @@ pub struct Eip1559EthereumData {
 // manual impl of debug for the hex encoding of everything.
 impl fmt::Debug for Eip1559EthereumData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
-
-        impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
-            }
-        }
-
         let Self {
             chain_id,
             nonce,
@@ -245,7 +430,7 @@ impl fmt::Debug for Eip1559EthereumData {
             .field("to", &hex::encode(to))
             .field("value", &hex::encode(value))
             .field("call_data", &hex::encode(call_data))
-            .field("access_list", &HexList(access_list))
+            .field("access_list", access_list)
             .field("recovery_id", &hex::encode(recovery_id))
             .field("r", &hex::encode(r))
             .field("s", &hex::encode(s))
@@ -306,7 +491,7 @@ impl Eip1559EthereumData {
             .append(&self.to)
             .append(&self.value)
             .append(&self.call_data)
-            .append_list::<Vec<_>, _>(self.access_list.as_slice())
+            .append_list::<AccessListItem, _>(&self.access_list)
             .append(&self.recovery_id)
             .append(&self.r)
             .append(&self.s);
@@ -320,11 +505,20 @@ mod test {
     use expect_test::expect;
     use hex_literal::hex;
 
-    use crate::ethereum::EthereumData;
+    use crate::ethereum::{
+        AccessListItem,
+        Eip1559EthereumData,
+        Eip2930EthereumData,
+        EthereumData,
+    };
     // https://github.com/hashgraph/hedera-services/blob/1e01d9c6b8923639b41359c55413640b589c4ec7/hapi-utils/src/test/java/com/hedera/services/ethereum/EthTxDataTest.java#L49
     const RAW_TX_TYPE_0: &[u8]  =
         &hex!("f864012f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc18180827653820277a0f9fbff985d374be4a55f296915002eec11ac96f1ce2df183adf992baa9390b2fa00c1e867cc960d9c74ec2e6a662b7908ec4c8cc9f3091e886bcefbeb2290fb792");
 
+    // signed with the first hardhat development key.
+    const RAW_TX_TYPE_1: &[u8] =
+        &hex!("01f8c382012a022f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc1818083123456f85bf859947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181f842a00000000000000000000000000000000000000000000000000000000000000001a0000000000000000000000000000000000000000000000000000000000000000201a0f389805b2c73797dadd0575c4e4533f863f1d57ef9558f53ebb43f009351dacba044b20fcf698be94c30ebc01b955aa3564e757334b30e4b3caad3ac89a92e4167");
+
     const RAW_TX_TYPE_2: &[u8] =
         &hex!("02f87082012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a764000083123456c001a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01aaf7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66");
 
@@ -355,6 +549,58 @@ mod test {
         // assert_eq!(hex!("9ffbd69c44cf643ed8d1e756b505e545e3b5dd3a6b5ef9da1d8eca6679706594"), data.ethereum_hash);
     }
 
+    #[test]
+    fn eip2930_to_from_bytes() {
+        let data = EthereumData::from_bytes(RAW_TX_TYPE_1).unwrap();
+        assert_eq!(hex::encode(RAW_TX_TYPE_1), hex::encode(data.to_bytes()));
+
+        expect![[r#"
+            Eip2930(
+                Eip2930EthereumData {
+                    chain_id: "012a",
+                    nonce: "02",
+                    gas_price: "2f",
+                    gas_limit: "018000",
+                    to: "7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181",
+                    value: "",
+                    call_data: "123456",
+                    access_list: [
+                        AccessListItem {
+                            address: "7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181",
+                            storage_keys: [
+                                "0000000000000000000000000000000000000000000000000000000000000001",
+                                "0000000000000000000000000000000000000000000000000000000000000002",
+                            ],
+                        },
+                    ],
+                    recovery_id: "01",
+                    r: "f389805b2c73797dadd0575c4e4533f863f1d57ef9558f53ebb43f009351dacb",
+                    s: "44b20fcf698be94c30ebc01b955aa3564e757334b30e4b3caad3ac89a92e4167",
+                },
+            )
+        "#]]
+        .assert_debug_eq(&data);
+
+        let EthereumData::Eip2930(data) = data else { unreachable!() };
+        assert_eq!(hex::encode(RAW_TX_TYPE_1), hex::encode(data.to_bytes()));
+        assert_eq!(
+            hex::encode(Eip2930EthereumData::from_bytes(RAW_TX_TYPE_1).unwrap().to_bytes()),
+            hex::encode(RAW_TX_TYPE_1)
+        );
+
+        // not an eip 2930 transaction.
+        assert!(Eip2930EthereumData::from_bytes(RAW_TX_TYPE_2).is_err());
+    }
+
+    #[test]
+    fn unsupported_type() {
+        // eip 4844 (blob) transactions.
+        let mut bytes = RAW_TX_TYPE_2.to_vec();
+        bytes[0] = 0x03;
+
+        assert!(EthereumData::from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn eip1559_to_from_bytes() {
         let data = EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap();
@@ -380,4 +626,21 @@ mod test {
         "#]]
         .assert_debug_eq(&data);
     }
+
+    #[test]
+    fn eip1559_access_list() {
+        let mut data = Eip1559EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap();
+        let storage_key = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+
+        data.access_list = Vec::from([AccessListItem {
+            address: hex!("7e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181").to_vec(),
+            storage_keys: Vec::from([storage_key.to_vec()]),
+        }]);
+
+        let bytes = data.to_bytes();
+        let decoded = Eip1559EthereumData::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.access_list, data.access_list);
+        assert_eq!(hex::encode(decoded.to_bytes()), hex::encode(bytes));
+    }
 }
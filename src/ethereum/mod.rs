@@ -18,13 +18,18 @@
  * ‍
  */
 
+pub(crate) mod eip191;
 mod ethereum_data;
 mod ethereum_flow;
 mod ethereum_transaction;
 mod evm_address;
+#[cfg(feature = "serde")]
+mod typed_data;
 
 pub use ethereum_data::{
+    AccessListItem,
     Eip1559EthereumData,
+    Eip2930EthereumData,
     EthereumData,
     LegacyEthereumData,
 };
@@ -33,3 +38,5 @@ pub use ethereum_transaction::EthereumTransaction;
 pub(crate) use ethereum_transaction::EthereumTransactionData;
 pub use evm_address::EvmAddress;
 pub(crate) use evm_address::SolidityAddress;
+#[cfg(feature = "serde")]
+pub use typed_data::TypedData;
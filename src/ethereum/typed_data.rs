@@ -0,0 +1,475 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use num_bigint::{
+    BigInt,
+    Sign,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use super::eip191;
+use crate::Error;
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// The fields of `EIP712Domain`, in the order they're hashed in when the domain's type isn't given.
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// [EIP-712](https://eips.ethereum.org/EIPS/eip-712) typed structured data,
+/// such as a permit or a meta-transaction, for [`PrivateKey::sign_typed_data`](crate::PrivateKey::sign_typed_data).
+///
+/// The data is parsed from the JSON format of `eth_signTypedData_v4`, and hashed up front.
+///
+/// # Examples
+/// ```
+/// use hedera::TypedData;
+///
+/// let typed_data = TypedData::from_json(r#"{
+///     "types": {
+///         "EIP712Domain": [{ "name": "name", "type": "string" }, { "name": "chainId", "type": "uint256" }],
+///         "Greeting": [{ "name": "contents", "type": "string" }]
+///     },
+///     "primaryType": "Greeting",
+///     "domain": { "name": "Example", "chainId": 296 },
+///     "message": { "contents": "Hello, Hedera!" }
+/// }"#)?;
+///
+/// assert_eq!(typed_data.primary_type(), "Greeting");
+/// # Ok::<(), hedera::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedData {
+    primary_type: String,
+    domain_separator: [u8; 32],
+    struct_hash: Option<[u8; 32]>,
+}
+
+impl TypedData {
+    /// Parse and hash `TypedData` from the JSON format of `eth_signTypedData_v4`.
+    ///
+    /// If `types` doesn't include `EIP712Domain`, it's inferred from the fields of `domain`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't valid typed data,
+    ///   a type isn't known, or a value doesn't fit its type (or is missing).
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let TypedDataJson { mut types, primary_type, domain, message } =
+            serde_json::from_str(json).map_err(Error::basic_parse)?;
+
+        if !types.contains_key(DOMAIN_TYPE) {
+            let fields = DOMAIN_FIELDS
+                .iter()
+                .filter(|(name, _)| domain.contains_key(*name))
+                .map(|(name, ty)| FieldJson { name: (*name).to_owned(), ty: (*ty).to_owned() })
+                .collect();
+
+            types.insert(DOMAIN_TYPE.to_owned(), fields);
+        }
+
+        let encoder = Encoder { types: &types };
+
+        let domain_separator = encoder.hash_struct(DOMAIN_TYPE, &domain)?;
+
+        // the message of the domain itself isn't hashed.
+        let struct_hash = match primary_type.as_str() {
+            DOMAIN_TYPE => None,
+            _ => Some(encoder.hash_struct(&primary_type, &message)?),
+        };
+
+        Ok(Self { primary_type, domain_separator, struct_hash })
+    }
+
+    /// Returns the name of the type of the message.
+    #[must_use]
+    pub fn primary_type(&self) -> &str {
+        &self.primary_type
+    }
+
+    /// Returns `hashStruct(domain)`.
+    #[must_use]
+    pub fn domain_separator(&self) -> [u8; 32] {
+        self.domain_separator
+    }
+
+    /// Returns `hashStruct(message)`, or `None` if the primary type is `EIP712Domain`.
+    #[must_use]
+    pub fn struct_hash(&self) -> Option<[u8; 32]> {
+        self.struct_hash
+    }
+
+    /// Returns the hash that's actually signed, `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    #[must_use]
+    pub fn signing_hash(&self) -> [u8; 32] {
+        Keccak256::digest(self.to_signed_data()).into()
+    }
+
+    pub(crate) fn to_signed_data(&self) -> Vec<u8> {
+        eip191::structured_data(&self.domain_separator, self.struct_hash.as_ref())
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TypedDataJson {
+    types: BTreeMap<String, Vec<FieldJson>>,
+    primary_type: String,
+    domain: Map<String, Value>,
+    #[serde(default)]
+    message: Map<String, Value>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct FieldJson {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+fn invalid(message: String) -> Error {
+    Error::basic_parse(message)
+}
+
+/// Splits `Type[]` and `Type[n]` into the element type and the length (if fixed).
+fn split_array(ty: &str) -> crate::Result<Option<(&str, Option<usize>)>> {
+    let Some(inner) = ty.strip_suffix(']') else {
+        return Ok(None);
+    };
+
+    let (element, len) =
+        inner.rsplit_once('[').ok_or_else(|| invalid(format!("invalid type `{ty}`")))?;
+
+    let len = match len {
+        "" => None,
+        len => Some(len.parse().map_err(|_| invalid(format!("invalid array length in `{ty}`")))?),
+    };
+
+    Ok(Some((element, len)))
+}
+
+struct Encoder<'a> {
+    types: &'a BTreeMap<String, Vec<FieldJson>>,
+}
+
+impl Encoder<'_> {
+    fn fields(&self, ty: &str) -> crate::Result<&[FieldJson]> {
+        self.types.get(ty).map(Vec::as_slice).ok_or_else(|| invalid(format!("unknown type `{ty}`")))
+    }
+
+    /// Collects every struct type `ty` references, directly or indirectly.
+    fn collect_dependencies<'b>(&'b self, ty: &'b str, found: &mut BTreeSet<&'b str>) {
+        let mut base = ty;
+        while let Ok(Some((element, _))) = split_array(base) {
+            base = element;
+        }
+
+        let Some(fields) = self.types.get(base) else {
+            return;
+        };
+
+        if !found.insert(base) {
+            return;
+        }
+
+        for field in fields {
+            self.collect_dependencies(&field.ty, found);
+        }
+    }
+
+    /// `encodeType`, the primary type followed by every type it references, sorted by name.
+    fn encode_type(&self, ty: &str) -> crate::Result<String> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(ty, &mut dependencies);
+        dependencies.remove(ty);
+
+        let mut out = String::new();
+
+        for ty in std::iter::once(ty).chain(dependencies) {
+            let fields: Vec<_> = self
+                .fields(ty)?
+                .iter()
+                .map(|field| format!("{} {}", field.ty, field.name))
+                .collect();
+
+            out.push_str(&format!("{ty}({})", fields.join(",")));
+        }
+
+        Ok(out)
+    }
+
+    /// `hashStruct`, the keccak256 hash of the type hash followed by every field's encoded value.
+    fn hash_struct(&self, ty: &str, values: &Map<String, Value>) -> crate::Result<[u8; 32]> {
+        let mut hasher = Keccak256::new_with_prefix(Keccak256::digest(self.encode_type(ty)?));
+
+        for field in self.fields(ty)? {
+            let value = values
+                .get(&field.name)
+                .ok_or_else(|| invalid(format!("missing value for `{ty}.{}`", field.name)))?;
+
+            hasher.update(self.encode_value(&field.ty, value)?);
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    fn encode_value(&self, ty: &str, value: &Value) -> crate::Result<[u8; 32]> {
+        let mismatch = || invalid(format!("expected a value of type `{ty}`, found `{value}`"));
+
+        if let Some((element, len)) = split_array(ty)? {
+            let items = value.as_array().ok_or_else(mismatch)?;
+
+            if len.is_some_and(|len| len != items.len()) {
+                return Err(mismatch());
+            }
+
+            let mut hasher = Keccak256::new();
+            for item in items {
+                hasher.update(self.encode_value(element, item)?);
+            }
+
+            return Ok(hasher.finalize().into());
+        }
+
+        if self.types.contains_key(ty) {
+            return self.hash_struct(ty, value.as_object().ok_or_else(mismatch)?);
+        }
+
+        let mut out = [0; 32];
+
+        match ty {
+            "string" => return Ok(Keccak256::digest(value.as_str().ok_or_else(mismatch)?).into()),
+            "bytes" => return Ok(Keccak256::digest(decode_hex(value).ok_or_else(mismatch)?).into()),
+            "bool" => out[31] = u8::from(value.as_bool().ok_or_else(mismatch)?),
+            "address" => {
+                let address = decode_hex(value).filter(|it| it.len() == 20).ok_or_else(mismatch)?;
+                out[12..].copy_from_slice(&address);
+            }
+            _ => {
+                if let Some(size) = ty.strip_prefix("bytes") {
+                    let size: usize = size
+                        .parse()
+                        .ok()
+                        .filter(|it| (1..=32).contains(it))
+                        .ok_or_else(|| invalid(format!("unknown type `{ty}`")))?;
+
+                    let bytes =
+                        decode_hex(value).filter(|it| it.len() == size).ok_or_else(mismatch)?;
+                    out[..size].copy_from_slice(&bytes);
+
+                    return Ok(out);
+                }
+
+                let (signed, bits) = match ty.strip_prefix("int") {
+                    Some(bits) => (true, bits),
+                    None => (false, ty.strip_prefix("uint").unwrap_or(ty)),
+                };
+
+                let bits: u32 = match bits {
+                    // `uint` and `int` are aliases for `uint256` and `int256`.
+                    "" => 256,
+                    bits => bits
+                        .parse()
+                        .ok()
+                        .filter(|it| it % 8 == 0 && (8..=256).contains(it))
+                        .ok_or_else(|| invalid(format!("unknown type `{ty}`")))?,
+                };
+
+                return encode_int(&parse_int(value).ok_or_else(mismatch)?, signed, bits)
+                    .ok_or_else(mismatch);
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+fn decode_hex(value: &Value) -> Option<Vec<u8>> {
+    hex::decode(value.as_str()?.strip_prefix("0x")?).ok()
+}
+
+/// Integers can be JSON numbers, or decimal or `0x` prefixed hex strings (for values that don't fit in a number).
+fn parse_int(value: &Value) -> Option<BigInt> {
+    match value {
+        Value::Number(it) => {
+            it.as_u64().map(BigInt::from).or_else(|| it.as_i64().map(BigInt::from))
+        }
+        Value::String(it) => {
+            let (sign, it) = match it.strip_prefix('-') {
+                Some(it) => (Sign::Minus, it),
+                None => (Sign::Plus, it.as_str()),
+            };
+
+            let magnitude = match it.strip_prefix("0x") {
+                Some(it) => BigInt::parse_bytes(it.as_bytes(), 16)?,
+                None => BigInt::parse_bytes(it.as_bytes(), 10)?,
+            };
+
+            Some(if sign == Sign::Minus { -magnitude } else { magnitude })
+        }
+        _ => None,
+    }
+}
+
+/// Encodes `value` as a 256 bit two's complement integer, if it fits in a `bits` bit (un)signed integer.
+fn encode_int(value: &BigInt, signed: bool, bits: u32) -> Option<[u8; 32]> {
+    let (min, max) = if signed {
+        (-(BigInt::from(1) << (bits - 1)), BigInt::from(1) << (bits - 1))
+    } else {
+        (BigInt::from(0), BigInt::from(1) << bits)
+    };
+
+    if value < &min || value >= &max {
+        return None;
+    }
+
+    // the magnitude of a `uint256` can need all 32 bytes, so only negative values are sign extended.
+    let (fill, bytes) = match value.sign() {
+        Sign::Minus => (0xff, value.to_signed_bytes_be()),
+        _ => (0, value.to_bytes_be().1),
+    };
+
+    let mut out = [fill; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::TypedData;
+    use crate::PrivateKey;
+
+    // the example from EIP-712.
+    const MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    #[test]
+    fn mail() {
+        let typed_data = TypedData::from_json(MAIL).unwrap();
+
+        assert_eq!(
+            typed_data.domain_separator(),
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+        assert_eq!(
+            typed_data.struct_hash(),
+            Some(hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"))
+        );
+        assert_eq!(
+            typed_data.signing_hash(),
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+    }
+
+    #[test]
+    fn mail_signature() {
+        // `keccak256("cow")`
+        let key = PrivateKey::from_bytes_ecdsa(&hex!(
+            "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4"
+        ))
+        .unwrap();
+
+        let typed_data = TypedData::from_json(MAIL).unwrap();
+
+        let signature = key.sign_typed_data(&typed_data).unwrap();
+
+        assert_eq!(
+            signature,
+            hex!(
+                "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d"
+                "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562"
+                "1c"
+            )
+        );
+
+        key.public_key().verify_typed_data(&typed_data, &signature).unwrap();
+
+        assert!(PrivateKey::generate_ecdsa()
+            .public_key()
+            .verify_typed_data(&typed_data, &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn invalid_values() {
+        let mail: serde_json::Value = serde_json::from_str(MAIL).unwrap();
+
+        let with = |pointer: &str, value: serde_json::Value| {
+            let mut mail = mail.clone();
+            *mail.pointer_mut(pointer).unwrap() = value;
+            TypedData::from_json(&mail.to_string())
+        };
+
+        assert!(with("/domain/chainId", "0x1".into()).is_ok());
+        assert!(with("/domain/chainId", (-1).into()).is_err());
+        assert!(with("/message/to/wallet", "0xbBbB".into()).is_err());
+        assert!(with("/message/contents", 1.into()).is_err());
+        assert!(with("/message/from", serde_json::json!({ "name": "Cow" })).is_err());
+        assert!(with("/primaryType", "Letter".into()).is_err());
+    }
+}
@@ -22,6 +22,10 @@ mod error;
 use std::any::type_name;
 use std::borrow::Cow;
 use std::ops::ControlFlow;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
 use std::time::{
     Duration,
     Instant,
@@ -34,15 +38,31 @@ use backoff::{
 use futures_core::future::BoxFuture;
 use futures_util::StreamExt;
 use prost::Message;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
 use tonic::metadata::AsciiMetadataValue;
 use tonic::transport::Channel;
 use triomphe::Arc;
 
+use crate::circuit_breaker::{
+    CircuitBreakerConfig,
+    CircuitState,
+};
 use crate::client::NetworkData;
 use crate::execute::error::is_tonic_status_transient;
+use crate::interceptor::{
+    AttemptDecision,
+    AttemptInfo,
+    AttemptOutcome,
+    InterceptedRequest,
+    Interceptors,
+};
+use crate::network_stats::RequestMetrics;
+use crate::node_selector::NodeSelector;
 use crate::ping_query::PingQuery;
+use crate::retry_policy::{
+    FailedAttempt,
+    RetryDecision,
+    RetryPolicy,
+};
 use crate::{
     client,
     retry,
@@ -56,7 +76,7 @@ use crate::{
 };
 
 pub(crate) trait Execute: ValidateChecksums {
-    type GrpcRequest: Clone + Message;
+    type GrpcRequest: Clone + Message + 'static;
 
     type GrpcResponse: Message;
 
@@ -99,15 +119,22 @@ pub(crate) trait Execute: ValidateChecksums {
         false
     }
 
+    /// Returns the retry policy to use instead of the client's, if any.
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        None
+    }
+
     /// Create a new request for execution.
     ///
     /// A created request is cached per node until any request returns
     /// `TransactionExpired`; in which case, the request cache is cleared.
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    ///
+    /// This is asynchronous because creating a request may involve signing it with an asynchronous [`Signer`](crate::Signer).
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)>;
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>>;
 
     /// Execute the created GRPC request against the provided GRPC channel.
     fn execute(
@@ -146,6 +173,13 @@ struct ExecuteContext {
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    // connect to nodes over TLS.
+    transport_security: bool,
+    interceptors: Interceptors,
+    request_metrics: Arc<RequestMetrics>,
+    node_selector: Arc<dyn NodeSelector>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    retry_policy: Arc<dyn RetryPolicy>,
 }
 
 pub(crate) async fn execute<E>(
@@ -198,6 +232,12 @@ where
             operator_account_id,
             network: client.net().0.load_full(),
             grpc_timeout: backoff.grpc_timeout,
+            transport_security: client.transport_security(),
+            interceptors: client.interceptors(),
+            request_metrics: client.request_metrics(),
+            node_selector: client.node_selector(),
+            circuit_breaker: client.circuit_breaker(),
+            retry_policy: executable.retry_policy().unwrap_or_else(|| client.retry_policy()),
         },
         executable,
     )
@@ -216,6 +256,12 @@ where
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                transport_security: ctx.transport_security,
+                interceptors: Arc::clone(&ctx.interceptors),
+                request_metrics: Arc::clone(&ctx.request_metrics),
+                node_selector: Arc::clone(&ctx.node_selector),
+                circuit_breaker: ctx.circuit_breaker,
+                retry_policy: Arc::clone(&ctx.retry_policy),
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -226,6 +272,8 @@ where
     // the overall timeout for the backoff starts measuring from here
     let backoff = ctx.backoff_config.clone();
 
+    ctx.request_metrics.record_execution(type_name::<E>());
+
     // TODO: cache requests to avoid signing a new request for every node in a delayed back-off
 
     // if we need to generate a transaction ID for this request (and one was not provided),
@@ -237,7 +285,7 @@ where
         .and_then(|it| it.or_else(|| ctx.operator_account_id.map(TransactionId::generate)));
 
     // if we were explicitly given a list of nodes to use, we iterate through each
-    // of the given nodes (in a random order)
+    // of the given nodes (in the order the client's node selector picks)
     let explicit_node_indexes = executable
         .node_account_ids()
         .map(|ids| ctx.network.node_indexes_for_ids(ids))
@@ -245,31 +293,64 @@ where
 
     let explicit_node_indexes = explicit_node_indexes.as_deref();
 
+    // shared between every call to `layer`, so that attempts are counted across backoffs.
+    let attempts = AtomicUsize::new(0);
+    let attempts = &attempts;
+
     let layer = move || async move {
         loop {
             let mut last_error: Option<Error> = None;
 
-            let random_node_indexes = random_node_indexes(&ctx.network, explicit_node_indexes)
+            let node_indexes = ctx
+                .network
+                .select_node_indexes(
+                    &*ctx.node_selector,
+                    explicit_node_indexes,
+                    ctx.circuit_breaker.is_some(),
+                )
                 .ok_or(retry::Error::EmptyTransient)?;
 
-            let random_node_indexes = {
-                let random_node_indexes = &random_node_indexes;
+            let node_indexes = {
+                let node_indexes = &node_indexes;
                 let client = ctx;
                 let now = Instant::now();
-                futures_util::stream::iter(random_node_indexes.iter().copied()).filter(
+                futures_util::stream::iter(node_indexes.iter().copied()).filter(
                     move |&node_index| async move {
+                        // a half-open circuit needs to be probed before the node gets used again,
+                        // for explicit nodes the request itself is the probe.
+                        let half_open = client.circuit_breaker.is_some()
+                            && client.network.circuit_state(node_index, now)
+                                == CircuitState::HalfOpen;
+
                         // NOTE: For pings we're relying on the fact that they have an explict node index.
                         explicit_node_indexes.is_some()
-                            || client.network.node_recently_pinged(node_index, now)
+                            || (!half_open && client.network.node_recently_pinged(node_index, now))
                             || recurse_ping(client, node_index).await
                     },
                 )
             };
 
-            let mut random_node_indexes = std::pin::pin!(random_node_indexes);
+            let mut node_indexes = std::pin::pin!(node_indexes);
+
+            while let Some(node_index) = node_indexes.next().await {
+                let tmp = loop {
+                    let attempt = attempts.fetch_add(1, Ordering::Relaxed) + 1;
 
-            while let Some(node_index) = random_node_indexes.next().await {
-                let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
+                    if attempt > 1 {
+                        ctx.request_metrics.record_retry(type_name::<E>());
+                    }
+
+                    match execute_single(ctx, executable, node_index, attempt, &mut transaction_id)
+                        .await
+                    {
+                        Attempt::RetrySameNode(err) => log::warn!(
+                            "Execution of {} on node at index {node_index} / node id {} will be retried on the same node due to {err:?}",
+                            type_name::<E>(),
+                            ctx.network.node_ids()[node_index],
+                        ),
+                        Attempt::Done(it) => break it,
+                    }
+                };
 
                 log::log!(
                     match &tmp {
@@ -284,7 +365,7 @@ where
                     },
                     "Execution of {} on node at index {node_index} / node id {} {}",
                     type_name::<E>(),
-                    ctx.network.channel(node_index).0,
+                    ctx.network.node_ids()[node_index],
                     match &tmp {
                         Ok(ControlFlow::Break(_)) => Cow::Borrowed("succeeded"),
                         Ok(ControlFlow::Continue(err)) =>
@@ -310,7 +391,13 @@ where
     // the outer loop continues until we timeout or reach the maximum number of "attempts"
     // an attempt is counted when we have a successful response from a node that must either
     // be retried immediately (on a new node) or retried after a backoff.
-    crate::retry(backoff, Some(ctx.max_attempts), layer).await
+    let res = crate::retry(backoff, Some(ctx.max_attempts), layer).await;
+
+    if let Err(Error::TimedOut(_)) = &res {
+        ctx.request_metrics.record_timeout(type_name::<E>());
+    }
+
+    res
 }
 
 fn map_tonic_error(
@@ -361,157 +448,277 @@ fn map_tonic_error(
     }
 }
 
+/// The result of a single attempt, see [`execute_single`].
+enum Attempt<T> {
+    /// The retry policy wants the same node to be tried again, immediately.
+    RetrySameNode(Error),
+    Done(retry::Result<ControlFlow<T, Error>>),
+}
+
 async fn execute_single<E: Execute + Sync>(
     ctx: &ExecuteContext,
     executable: &E,
     node_index: usize,
+    attempt: usize,
     transaction_id: &mut Option<TransactionId>,
-) -> retry::Result<ControlFlow<E::Response, Error>> {
-    let (node_account_id, channel) = ctx.network.channel(node_index);
+) -> Attempt<E::Response> {
+    let (node_account_id, channel) = ctx.network.channel(node_index, ctx.transport_security);
 
     log::debug!(
         "Preparing {} on node at index {node_index} / node id {node_account_id}",
         type_name::<E>()
     );
 
-    let (request, context) = executable
-        .make_request(transaction_id.as_ref(), node_account_id)
-        // Does not represent a network error or error returned by a node
-        .map_err(retry::Error::Permanent)?;
+    let (mut request, context) =
+        match executable.make_request(transaction_id.as_ref(), node_account_id).await {
+            Ok(it) => it,
+            // Does not represent a network error or error returned by a node
+            Err(e) => return Attempt::Done(Err(retry::Error::Permanent(e))),
+        };
+
+    let info = AttemptInfo { request_type: type_name::<E>(), node_account_id, attempt };
+
+    for interceptor in ctx.interceptors.iter() {
+        interceptor.before_attempt(&info, &mut InterceptedRequest::new(&mut request));
+    }
 
     log::debug!(
         "Executing {} on node at index {node_index} / node id {node_account_id}",
         type_name::<E>()
     );
 
-    let fut = executable.execute(channel, request);
+    // an `async` block so that every early return below still gets reported to the interceptors.
+    let result: retry::Result<ControlFlow<E::Response, Error>> = async {
+        let fut = executable.execute(channel, request);
 
-    let response = match ctx.grpc_timeout {
-        Some(it) => match tokio::time::timeout(it, fut).await {
-            Ok(it) => it,
-            Err(_) => {
-                return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
-                    tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
-                )))
-            }
-        },
-        None => fut.await,
-    };
+        let started_at = Instant::now();
 
-    let response = response.map(tonic::Response::into_inner).map_err(|status| {
-        map_tonic_error(status, &ctx.network, node_index, transaction_id.is_none())
-    });
+        let response = match ctx.grpc_timeout {
+            Some(it) => match tokio::time::timeout(it, fut).await {
+                Ok(it) => it,
+                Err(_) => {
+                    ctx.request_metrics.record_timeout(type_name::<E>());
 
-    let response = match response {
-        Ok(response) => response,
-        Err(retry::Error::Transient(err)) => {
-            return Ok(ControlFlow::Continue(err));
-        }
+                    return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
+                        tonic::Status::deadline_exceeded(
+                            "explicitly given grpc timeout was exceeded",
+                        ),
+                    )));
+                }
+            },
+            None => fut.await,
+        };
 
-        Err(e) => return Err(e),
-    };
+        ctx.network.record_node_latency(node_index, started_at.elapsed());
 
-    // at this point, any failure isn't from the node, it's from the request.
-    ctx.network.mark_node_healthy(node_index);
+        let response = response.map(tonic::Response::into_inner).map_err(|status| {
+            map_tonic_error(status, &ctx.network, node_index, transaction_id.is_none())
+        });
 
-    let status = E::response_pre_check_status(&response)
-        .and_then(|status| {
-            // not sure how to proceed, fail immediately
-            Status::try_from(status).or_else(|_| Err(Error::ResponseStatusUnrecognized(status)))
-        })
-        .map_err(retry::Error::Permanent)?;
+        let response = match response {
+            Ok(response) => response,
+            Err(retry::Error::Transient(err)) => {
+                return Ok(ControlFlow::Continue(err));
+            }
+
+            Err(e) => return Err(e),
+        };
+
+        // at this point, any failure isn't from the node, it's from the request.
+        ctx.network.mark_node_healthy(node_index);
+
+        let status = E::response_pre_check_status(&response)
+            .and_then(|status| {
+                // not sure how to proceed, fail immediately
+                Status::try_from(status).or_else(|_| Err(Error::ResponseStatusUnrecognized(status)))
+            })
+            .map_err(retry::Error::Permanent)?;
+
+        match status {
+            Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
+                executable.make_error_pre_check(status, transaction_id.as_ref(), response),
+            )),
+
+            Status::Ok => executable
+                .make_response(response, context, node_account_id, transaction_id.as_ref())
+                .map(ControlFlow::Break)
+                .map_err(retry::Error::Permanent),
+
+            Status::Busy | Status::PlatformNotActive => {
+                // NOTE: this is a "busy" node
+                // try the next node in our allowed list, immediately
+                Ok(ControlFlow::Continue(executable.make_error_pre_check(
+                    status,
+                    transaction_id.as_ref(),
+                    response,
+                )))
+            }
 
-    match status {
-        Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
-            executable.make_error_pre_check(status, transaction_id.as_ref(), response),
-        )),
+            // would do an `if_let` but, not stable ._.
+            Status::TransactionExpired if ctx.operator_account_id.is_some() => {
+                // the transaction that was generated has since expired
+                // re-generate the transaction ID and try again, immediately
 
-        Status::Ok => executable
-            .make_response(response, context, node_account_id, transaction_id.as_ref())
-            .map(ControlFlow::Break)
-            .map_err(retry::Error::Permanent),
+                let new = TransactionId::generate(ctx.operator_account_id.unwrap());
 
-        Status::Busy | Status::PlatformNotActive => {
-            // NOTE: this is a "busy" node
-            // try the next node in our allowed list, immediately
-            Ok(ControlFlow::Continue(executable.make_error_pre_check(
-                status,
-                transaction_id.as_ref(),
-                response,
-            )))
-        }
+                *transaction_id = Some(new);
+
+                Ok(ControlFlow::Continue(executable.make_error_pre_check(
+                    status,
+                    transaction_id.as_ref(),
+                    response,
+                )))
+            }
 
-        // would do an `if_let` but, not stable ._.
-        Status::TransactionExpired if ctx.operator_account_id.is_some() => {
-            // the transaction that was generated has since expired
-            // re-generate the transaction ID and try again, immediately
+            _ if executable.should_retry_pre_check(status) => {
+                // conditional retry on pre-check should back-off and try again
+                Err(retry::Error::Transient(executable.make_error_pre_check(
+                    status,
+                    transaction_id.as_ref(),
+                    response,
+                )))
+            }
 
-            let new = TransactionId::generate(ctx.operator_account_id.unwrap());
+            _ => {
+                // any other pre-check is an error that the user needs to fix, fail immediately
+                Err(retry::Error::Permanent(executable.make_error_pre_check(
+                    status,
+                    transaction_id.as_ref(),
+                    response,
+                )))
+            }
+        }
+    }
+    .await;
+
+    // the retry policy has the final say on what happens after a failure.
+    let decision = retry_decision(ctx, &info, &result);
+
+    let result = match (decision, result) {
+        (
+            Some(decision),
+            Ok(ControlFlow::Continue(err))
+            | Err(retry::Error::Transient(err) | retry::Error::Permanent(err)),
+        ) => match decision {
+            RetryDecision::RetrySameNode | RetryDecision::RetryNextNode => {
+                Ok(ControlFlow::Continue(err))
+            }
+            RetryDecision::Backoff => Err(retry::Error::Transient(err)),
+            RetryDecision::Fail => Err(retry::Error::Permanent(err)),
+        },
+        (_, result) => result,
+    };
 
-            *transaction_id = Some(new);
+    let retry_same_node = decision == Some(RetryDecision::RetrySameNode);
 
-            Ok(ControlFlow::Continue(executable.make_error_pre_check(
-                status,
-                transaction_id.as_ref(),
-                response,
-            )))
+    let reported = match &result {
+        Ok(ControlFlow::Break(_)) => {
+            Some((AttemptOutcome::PreCheck(Status::Ok), AttemptDecision::Succeeded))
+        }
+        Ok(ControlFlow::Continue(err)) if retry_same_node => {
+            Some((AttemptOutcome::from_error(err), AttemptDecision::RetrySameNode))
+        }
+        Ok(ControlFlow::Continue(err)) => {
+            Some((AttemptOutcome::from_error(err), AttemptDecision::RetryNextNode))
+        }
+        Err(retry::Error::Transient(err)) => {
+            Some((AttemptOutcome::from_error(err), AttemptDecision::Backoff))
+        }
+        Err(retry::Error::Permanent(err)) => {
+            Some((AttemptOutcome::from_error(err), AttemptDecision::Fail))
         }
+        // never produced for a single attempt.
+        Err(retry::Error::EmptyTransient) => None,
+    };
 
-        _ if executable.should_retry_pre_check(status) => {
-            // conditional retry on pre-check should back-off and try again
-            Err(retry::Error::Transient(executable.make_error_pre_check(
-                status,
-                transaction_id.as_ref(),
-                response,
-            )))
+    if let Some(config) = &ctx.circuit_breaker {
+        if let Some(failed) = node_failed(&result) {
+            ctx.network.record_circuit_breaker(node_index, config, failed);
         }
+    }
 
-        _ => {
-            // any other pre-check is an error that the user needs to fix, fail immediately
-            Err(retry::Error::Permanent(executable.make_error_pre_check(
-                status,
-                transaction_id.as_ref(),
-                response,
-            )))
+    match &result {
+        Ok(ControlFlow::Break(_)) => ctx.network.record_node_success(node_index),
+        Ok(ControlFlow::Continue(err))
+        | Err(retry::Error::Transient(err) | retry::Error::Permanent(err)) => {
+            ctx.network.record_node_failure(node_index, err);
         }
+        Err(retry::Error::EmptyTransient) => {}
     }
-}
 
-// todo: return an iterator.
-fn random_node_indexes(
-    network: &client::NetworkData,
-    explicit_node_indexes: Option<&[usize]>,
-) -> Option<Vec<usize>> {
-    // cache the rng impl and "now" because `thread_rng` is TLS (a thread local),
-    // and because using the same reference time avoids situations where a node that wasn't available becomes available.
-    let mut rng = thread_rng();
-    let now = Instant::now();
+    if let Some((outcome, decision)) = reported {
+        for interceptor in ctx.interceptors.iter() {
+            interceptor.after_attempt(&info, outcome, decision);
+        }
+    }
 
-    if let Some(indexes) = explicit_node_indexes {
-        let tmp: Vec<_> =
-            indexes.iter().copied().filter(|index| network.is_node_healthy(*index, now)).collect();
+    match result {
+        Ok(ControlFlow::Continue(err)) if retry_same_node => Attempt::RetrySameNode(err),
+        result => Attempt::Done(result),
+    }
+}
 
-        let mut indexes = if tmp.is_empty() { indexes.to_vec() } else { tmp };
+/// Asks the retry policy what to do after an attempt, or returns `None` if it isn't up to the policy.
+fn retry_decision<T>(
+    ctx: &ExecuteContext,
+    info: &AttemptInfo,
+    result: &retry::Result<ControlFlow<T, Error>>,
+) -> Option<RetryDecision> {
+    let (err, default_decision) = match result {
+        Ok(ControlFlow::Break(_)) | Err(retry::Error::EmptyTransient) => return None,
+        Ok(ControlFlow::Continue(err)) => (err, RetryDecision::RetryNextNode),
+        Err(retry::Error::Transient(err)) => (err, RetryDecision::Backoff),
+        Err(retry::Error::Permanent(err)) => (err, RetryDecision::Fail),
+    };
 
-        assert!(!indexes.is_empty(), "empty explicitly set nodes");
+    let (status, grpc_code) = match AttemptOutcome::from_error(err) {
+        // `OK` here means `should_retry` wants to try again later, which isn't a failure.
+        AttemptOutcome::PreCheck(Status::Ok) | AttemptOutcome::Error(_) => return None,
+        AttemptOutcome::PreCheck(status) => (Some(status), None),
+        AttemptOutcome::Grpc(status) => (None, Some(status.code())),
+    };
 
-        indexes.shuffle(&mut rng);
+    let decision = ctx.retry_policy.decide(&FailedAttempt {
+        request_type: info.request_type,
+        node_account_id: info.node_account_id,
+        attempt: info.attempt,
+        status,
+        grpc_code,
+        default_decision,
+    });
 
-        return Some(indexes);
+    match decision {
+        RetryDecision::RetrySameNode if info.attempt >= ctx.max_attempts => {
+            Some(RetryDecision::RetryNextNode)
+        }
+        decision => Some(decision),
     }
+}
 
-    {
-        let mut indexes: Vec<_> = network.healthy_node_indexes(now).collect();
+/// Returns whether `result` says the node itself failed, or `None` if it says nothing about the node.
+///
+/// A node that rejects a request because of the request (for instance `INVALID_SIGNATURE`) is working as intended.
+fn node_failed<T>(result: &retry::Result<ControlFlow<T, Error>>) -> Option<bool> {
+    let err = match result {
+        Ok(ControlFlow::Break(_)) => return Some(false),
+        Ok(ControlFlow::Continue(err))
+        | Err(retry::Error::Transient(err) | retry::Error::Permanent(err)) => err,
+        Err(retry::Error::EmptyTransient) => return None,
+    };
 
-        if indexes.is_empty() {
-            return None;
+    match AttemptOutcome::from_error(err) {
+        AttemptOutcome::PreCheck(status) => {
+            Some(matches!(status, Status::Busy | Status::PlatformNotActive))
         }
-
-        // would put this inline, but borrowck wouldn't allow that.
-        let amount = (indexes.len() + 2) / 3;
-
-        let (shuffled, _) = indexes.partial_shuffle(&mut rng, amount);
-
-        Some(shuffled.to_vec())
+        AttemptOutcome::Grpc(status) => match status.code() {
+            tonic::Code::Unavailable
+            | tonic::Code::ResourceExhausted
+            | tonic::Code::DeadlineExceeded
+            | tonic::Code::Internal
+            | tonic::Code::Unknown => Some(true),
+            _ if is_tonic_status_transient(status) => Some(true),
+            _ => None,
+        },
+        _ => None,
     }
 }
@@ -0,0 +1,226 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::any::Any;
+use std::fmt;
+
+use prost::Message;
+use triomphe::Arc;
+
+use crate::{
+    AccountId,
+    Error,
+    Status,
+};
+
+/// The interceptors configured for a client, in the order they're called.
+pub(crate) type Interceptors = Arc<Vec<Arc<dyn Interceptor>>>;
+
+/// Hooks into every attempt the [`Client`](crate::Client) makes at executing a request against a node.
+///
+/// Interceptors are called in the order they were added with
+/// [`Client::add_interceptor`](crate::Client::add_interceptor),
+/// they're called for every kind of request, including the pings the client makes to check node health.
+///
+/// Both methods are called inline with execution, so they should be quick and must not block.
+pub trait Interceptor: Send + Sync {
+    /// Called right before `request` is sent to the node described by `info`.
+    ///
+    /// `request` may be modified, the modified request is what gets sent.
+    #[allow(unused_variables)]
+    fn before_attempt(&self, info: &AttemptInfo, request: &mut InterceptedRequest<'_>) {}
+
+    /// Called after an attempt completes with what happened and what the client is going to do next.
+    #[allow(unused_variables)]
+    fn after_attempt(
+        &self,
+        info: &AttemptInfo,
+        outcome: AttemptOutcome<'_>,
+        decision: AttemptDecision,
+    ) {
+    }
+}
+
+/// Describes a single attempt at executing a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AttemptInfo {
+    /// The name of the type being executed, such as `hedera::Transaction<...>`.
+    ///
+    /// This is meant for diagnostics only, the exact value isn't stable.
+    pub request_type: &'static str,
+
+    /// The account ID of the node the attempt is made against.
+    pub node_account_id: AccountId,
+
+    /// The attempt number, starting at 1 for the first attempt of each execution.
+    pub attempt: usize,
+}
+
+/// The protobuf request that's about to be sent to a node.
+///
+/// For transactions this is a `hedera_proto::services::Transaction`,
+/// and for queries it's a `hedera_proto::services::Query`.
+pub struct InterceptedRequest<'a> {
+    inner: &'a mut dyn AnyMessage,
+}
+
+impl<'a> InterceptedRequest<'a> {
+    pub(crate) fn new<M: Message + 'static>(request: &'a mut M) -> Self {
+        Self { inner: request }
+    }
+
+    /// Returns the protobuf encoded bytes of the request.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.inner.encode()
+    }
+
+    /// Returns a reference to the request if it's a `T`.
+    #[must_use]
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref()
+    }
+
+    /// Returns a mutable reference to the request if it's a `T`.
+    pub fn downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.inner.as_any_mut().downcast_mut()
+    }
+}
+
+impl fmt::Debug for InterceptedRequest<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InterceptedRequest").field(&self.inner).finish()
+    }
+}
+
+trait AnyMessage: fmt::Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    fn encode(&self) -> Vec<u8>;
+}
+
+impl<M: Message + 'static> AnyMessage for M {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        self.encode_to_vec()
+    }
+}
+
+/// What happened during an attempt.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum AttemptOutcome<'a> {
+    /// The node responded with the given pre-check status.
+    ///
+    /// Successful attempts are reported as [`Status::Ok`].
+    PreCheck(Status),
+
+    /// The gRPC request itself failed, including when the gRPC timeout is exceeded.
+    Grpc(&'a tonic::Status),
+
+    /// The attempt failed for some other reason, such as a response that couldn't be parsed.
+    Error(&'a Error),
+}
+
+impl<'a> AttemptOutcome<'a> {
+    pub(crate) fn from_error(error: &'a Error) -> Self {
+        match error {
+            Error::GrpcStatus(status) => Self::Grpc(status),
+            Error::TransactionPreCheckStatus { status, .. }
+            | Error::QueryPreCheckStatus { status, .. }
+            | Error::QueryPaymentPreCheckStatus { status, .. }
+            | Error::QueryNoPaymentPreCheckStatus { status } => Self::PreCheck(*status),
+            _ => Self::Error(error),
+        }
+    }
+}
+
+/// What the client decided to do after an attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AttemptDecision {
+    /// The attempt succeeded, execution is complete.
+    Succeeded,
+
+    /// The request will be retried immediately on the same node.
+    RetrySameNode,
+
+    /// The request will be retried immediately on the next node.
+    RetryNextNode,
+
+    /// The client will back off before trying again.
+    Backoff,
+
+    /// Execution failed, the error is returned to the caller.
+    Fail,
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::{
+        AttemptOutcome,
+        InterceptedRequest,
+    };
+    use crate::{
+        Error,
+        Status,
+    };
+
+    #[test]
+    fn intercepted_request_downcast() {
+        let mut transaction = services::Transaction::default();
+        let mut request = InterceptedRequest::new(&mut transaction);
+
+        assert!(request.downcast_ref::<services::Query>().is_none());
+
+        request.downcast_mut::<services::Transaction>().unwrap().signed_transaction_bytes =
+            Vec::from([1, 2, 3]);
+
+        let bytes = request.to_bytes();
+
+        assert_eq!(transaction.signed_transaction_bytes, [1, 2, 3]);
+        assert_eq!(bytes, transaction.encode_to_vec());
+    }
+
+    #[test]
+    fn outcome_from_error() {
+        let err = Error::QueryNoPaymentPreCheckStatus { status: Status::Busy };
+        assert!(matches!(AttemptOutcome::from_error(&err), AttemptOutcome::PreCheck(Status::Busy)));
+
+        let err = Error::GrpcStatus(tonic::Status::unavailable("unavailable"));
+        assert!(matches!(AttemptOutcome::from_error(&err), AttemptOutcome::Grpc(_)));
+
+        let err = Error::ResponseStatusUnrecognized(-1);
+        assert!(matches!(AttemptOutcome::from_error(&err), AttemptOutcome::Error(_)));
+    }
+}
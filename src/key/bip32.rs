@@ -0,0 +1,208 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) helpers for secp256k1 keys.
+
+use hmac::{
+    Hmac,
+    Mac,
+};
+use k256::elliptic_curve::PrimeField;
+use ripemd::Ripemd160;
+use sha2::{
+    Digest,
+    Sha256,
+    Sha512,
+};
+use zeroize::Zeroizing;
+
+use crate::Error;
+
+/// Indexes with this bit set are hardened.
+pub(super) const HARDENED: u32 = 1 << 31;
+
+/// Version bytes of a mainnet extended private key (`xprv`).
+pub(super) const XPRV: [u8; 4] = [0x04, 0x88, 0xad, 0xe4];
+
+/// Version bytes of a mainnet extended public key (`xpub`).
+pub(super) const XPUB: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+
+/// Where a key sits in its tree, which is part of its extended key serialization.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct KeyOrigin {
+    pub(crate) depth: u8,
+    pub(crate) parent_fingerprint: [u8; 4],
+    pub(crate) child_number: u32,
+}
+
+impl KeyOrigin {
+    /// Returns the origin of the child at `index` of the key with the (serialized) public key `parent`.
+    pub(super) fn child(&self, parent: &[u8], index: u32) -> crate::Result<Self> {
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or_else(|| Error::key_derive("keys can't be derived more than 255 levels deep"))?;
+
+        let hash = Ripemd160::digest(Sha256::digest(parent));
+
+        Ok(Self { depth, parent_fingerprint: hash[..4].try_into().unwrap(), child_number: index })
+    }
+}
+
+fn invalid() -> Error {
+    Error::key_derive(
+        "derived key is invalid (this is astronomically unlikely), use the next index instead",
+    )
+}
+
+/// Splits `HMAC-SHA512(key, data...)` into `IL` as a secp256k1 scalar, and `IR`.
+fn hmac_split(key: &[u8], data: &[&[u8]]) -> crate::Result<(k256::Scalar, [u8; 32])> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC can take keys of any size");
+
+    for data in data {
+        mac.update(data);
+    }
+
+    let output: Zeroizing<[u8; 64]> = Zeroizing::new(mac.finalize().into_bytes().into());
+
+    let (il, ir) = output.split_at(32);
+
+    let il = k256::FieldBytes::clone_from_slice(il);
+
+    // `IL` is only valid if it's less than the curve order.
+    let il = Option::<k256::Scalar>::from(k256::Scalar::from_repr(il)).ok_or_else(invalid)?;
+
+    Ok((il, ir.try_into().unwrap()))
+}
+
+/// Returns the master key and chain code for `seed`.
+pub(super) fn master_key(seed: &[u8]) -> crate::Result<(k256::ecdsa::SigningKey, [u8; 32])> {
+    let (key, chain_code) = hmac_split(b"Bitcoin seed", &[seed])?;
+
+    let key =
+        Option::<k256::NonZeroScalar>::from(k256::NonZeroScalar::new(key)).ok_or_else(invalid)?;
+
+    Ok((k256::ecdsa::SigningKey::from(key), chain_code))
+}
+
+/// `CKDpriv`, returns the child key and chain code of `key` at `index`.
+pub(super) fn derive_private(
+    key: &k256::ecdsa::SigningKey,
+    chain_code: &[u8; 32],
+    index: u32,
+) -> crate::Result<(k256::ecdsa::SigningKey, [u8; 32])> {
+    let index_bytes = index.to_be_bytes();
+
+    let (tweak, chain_code) = if index & HARDENED != 0 {
+        hmac_split(chain_code, &[&[0], key.to_bytes().as_slice(), &index_bytes])?
+    } else {
+        let public = key.verifying_key().to_encoded_point(true);
+
+        hmac_split(chain_code, &[public.as_bytes(), &index_bytes])?
+    };
+
+    let child = tweak + key.as_nonzero_scalar().as_ref();
+    let child =
+        Option::<k256::NonZeroScalar>::from(k256::NonZeroScalar::new(child)).ok_or_else(invalid)?;
+
+    Ok((k256::ecdsa::SigningKey::from(child), chain_code))
+}
+
+/// `CKDpub`, returns the child key and chain code of `key` at `index`.
+///
+/// # Errors
+/// - [`Error::KeyDerive`] if `index` is hardened, those can only be derived from private keys.
+pub(super) fn derive_public(
+    key: &k256::ecdsa::VerifyingKey,
+    chain_code: &[u8; 32],
+    index: u32,
+) -> crate::Result<(k256::ecdsa::VerifyingKey, [u8; 32])> {
+    if index & HARDENED != 0 {
+        return Err(Error::key_derive("hardened keys can't be derived from a public key"));
+    }
+
+    let public = key.to_encoded_point(true);
+
+    let (tweak, chain_code) = hmac_split(chain_code, &[public.as_bytes(), &index.to_be_bytes()])?;
+
+    let child = k256::ProjectivePoint::GENERATOR * tweak + key.as_affine();
+
+    let child = k256::ecdsa::VerifyingKey::from_affine(k256::AffinePoint::from(child))
+        .map_err(|_| invalid())?;
+
+    Ok((child, chain_code))
+}
+
+/// Serializes an extended key, `key` is either `0x00 || private key` or a compressed public key.
+pub(super) fn encode(
+    version: [u8; 4],
+    origin: &KeyOrigin,
+    chain_code: &[u8; 32],
+    key: &[u8; 33],
+) -> String {
+    // the key is private for an xprv.
+    let mut bytes = Zeroizing::new(Vec::with_capacity(78));
+
+    bytes.extend_from_slice(&version);
+    bytes.push(origin.depth);
+    bytes.extend_from_slice(&origin.parent_fingerprint);
+    bytes.extend_from_slice(&origin.child_number.to_be_bytes());
+    bytes.extend_from_slice(chain_code);
+    bytes.extend_from_slice(key);
+
+    bs58::encode(&*bytes).with_check().into_string()
+}
+
+/// Parses an extended key with the given `version`, the reverse of [`encode`].
+///
+/// # Errors
+/// - [`Error::KeyParse`] if `s` isn't valid Base58Check, isn't 78 bytes, or has the wrong version.
+pub(super) fn decode(
+    s: &str,
+    version: [u8; 4],
+) -> crate::Result<(KeyOrigin, [u8; 32], Zeroizing<[u8; 33]>)> {
+    // the key is private for an xprv.
+    let bytes =
+        Zeroizing::new(bs58::decode(s).with_check(None).into_vec().map_err(Error::key_parse)?);
+
+    let bytes: Zeroizing<[u8; 78]> = Zeroizing::new(bytes.as_slice().try_into().map_err(|_| {
+        Error::key_parse(format!("expected an extended key of 78 bytes, got {}", bytes.len()))
+    })?);
+
+    if bytes[..4] != version {
+        let expected = if version == XPRV { "xprv" } else { "xpub" };
+        return Err(Error::key_parse(format!(
+            "unsupported extended key version `{}`, expected an {expected}",
+            hex::encode(&bytes[..4])
+        )));
+    }
+
+    let origin = KeyOrigin {
+        depth: bytes[4],
+        parent_fingerprint: bytes[5..9].try_into().unwrap(),
+        child_number: u32::from_be_bytes(bytes[9..13].try_into().unwrap()),
+    };
+
+    if origin.depth == 0 && (origin.parent_fingerprint != [0; 4] || origin.child_number != 0) {
+        return Err(Error::key_parse("master key has a parent fingerprint or child number"));
+    }
+
+    Ok((origin, bytes[13..45].try_into().unwrap(), Zeroizing::new(bytes[45..].try_into().unwrap())))
+}
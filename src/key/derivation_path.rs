@@ -0,0 +1,117 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::bip32::HARDENED;
+use crate::Error;
+
+/// A BIP-32 derivation path, such as `m/44'/3030'/0'/0'/0'`.
+///
+/// Hardened indexes are written with a trailing `'` (`h` and `H` are also accepted when parsing).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// Create a new `DerivationPath` from raw indexes, where an index is hardened if its highest bit is set.
+    #[must_use]
+    pub fn new(indexes: Vec<u32>) -> Self {
+        Self(indexes)
+    }
+
+    /// Returns the indexes of this path, where an index is hardened if its highest bit is set.
+    #[must_use]
+    pub fn indexes(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split('/');
+
+        if components.next() != Some("m") {
+            return Err(Error::basic_parse("derivation path must start with `m`"));
+        }
+
+        components
+            .map(|component| {
+                let (index, hardened) = match component.strip_suffix(['\'', 'h', 'H']) {
+                    Some(index) => (index, true),
+                    None => (component, false),
+                };
+
+                let index = match index.parse::<u32>() {
+                    Ok(index) if index < HARDENED => index,
+                    _ => {
+                        return Err(Error::basic_parse(format!(
+                            "invalid derivation path component `{component}`"
+                        )))
+                    }
+                };
+
+                Ok(if hardened { index | HARDENED } else { index })
+            })
+            .collect::<crate::Result<_>>()
+            .map(Self)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+
+        for &index in &self.0 {
+            match index & HARDENED != 0 {
+                true => write!(f, "/{}'", index & !HARDENED)?,
+                false => write!(f, "/{index}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::DerivationPath;
+
+    #[test]
+    fn parse_display() {
+        let path = DerivationPath::from_str("m/44'/3030h/0H/0/7").unwrap();
+
+        assert_eq!(path.indexes(), [44 | (1 << 31), 3030 | (1 << 31), 1 << 31, 0, 7]);
+        assert_eq!(path.to_string(), "m/44'/3030'/0'/0/7");
+
+        assert_eq!(DerivationPath::from_str("m").unwrap(), DerivationPath::default());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for path in ["", "44'/0", "m/", "m/-1", "m/2147483648", "m/1''", "m/a"] {
+            assert!(DerivationPath::from_str(path).is_err(), "{path}");
+        }
+    }
+}
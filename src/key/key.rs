@@ -21,6 +21,11 @@
 use hedera_proto::services;
 
 use crate::contract::DelegateContractId;
+use crate::signer::sign_now;
+use crate::transaction::{
+    TransactionExecute,
+    TransactionSources,
+};
 use crate::{
     ContractId,
     Error,
@@ -28,6 +33,7 @@ use crate::{
     KeyList,
     PublicKey,
     ToProtobuf,
+    Transaction,
 };
 
 /// Any method that can be used to authorize an operation on Hedera.
@@ -48,12 +54,162 @@ pub enum Key {
     KeyList(KeyList),
 }
 
+/// Whether a [`Key`] is satisfied by a set of signatures, and if not, what's missing.
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct KeySatisfaction {
+    /// `true` if enough keys signed, honoring the thresholds of any key lists.
+    ///
+    /// Empty key lists, and key lists with a threshold of 0, are never satisfied.
+    pub is_satisfied: bool,
+
+    /// The keys that haven't signed, empty if the key is satisfied.
+    ///
+    /// These are the innermost keys (so [`Key::Single`], [`Key::ContractId`] and [`Key::DelegateContractId`])
+    /// of the key lists that aren't satisfied, when a key list has a threshold signatures from only some of them are needed.
+    pub missing: Vec<Key>,
+}
+
+impl KeySatisfaction {
+    /// Returns an [`Error::SignatureVerify`] listing the missing keys if the key isn't satisfied.
+    pub(crate) fn into_result(self) -> crate::Result<()> {
+        if self.is_satisfied {
+            return Ok(());
+        }
+
+        Err(Error::signature_verify(format!(
+            "key not satisfied, missing signatures from: {:?}",
+            self.missing
+        )))
+    }
+}
+
 impl Key {
     /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns whether `transaction` is signed by enough of this key to authorize it.
+    ///
+    /// A public key only counts as having signed if it has a valid signature for every node and chunk of the transaction.
+    /// Contract IDs can't sign transactions, so they never count as having signed.
+    ///
+    /// # Errors
+    /// - If [`Transaction::signatures`] would error for this transaction.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    pub fn is_satisfied_by<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<KeySatisfaction> {
+        assert!(
+            transaction.is_frozen(),
+            "Transaction must be frozen before calling `is_satisfied_by`"
+        );
+
+        let sources = sign_now(transaction.make_sources())?;
+
+        Ok(self.is_satisfied_by_sources(&sources))
+    }
+
+    /// Returns whether `msg` is signed by enough of this key to authorize it, given `(PublicKey, signature)` pairs.
+    ///
+    /// A public key counts as having signed if any of its signatures is valid for `msg`, invalid signatures are ignored.
+    /// Contract IDs can't sign messages, so they never count as having signed.
+    #[must_use]
+    pub fn is_satisfied_by_signatures<S: AsRef<[u8]>>(
+        &self,
+        msg: &[u8],
+        signatures: &[(PublicKey, S)],
+    ) -> KeySatisfaction {
+        self.satisfaction(&mut |key| {
+            signatures.iter().any(|(signer, signature)| {
+                signer == key && key.verify(msg, signature.as_ref()).is_ok()
+            })
+        })
+    }
+
+    /// Verify that `msg` is signed by enough of this key to authorize it, see [`is_satisfied_by_signatures`](Self::is_satisfied_by_signatures).
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if this key isn't satisfied by `signatures`.
+    pub fn verify_signatures<S: AsRef<[u8]>>(
+        &self,
+        msg: &[u8],
+        signatures: &[(PublicKey, S)],
+    ) -> crate::Result<()> {
+        self.is_satisfied_by_signatures(msg, signatures).into_result()
+    }
+
+    /// Returns whether the serialized transaction in `bytes` (see [`Transaction::to_bytes`]) is signed by enough of this key to authorize it.
+    ///
+    /// Works like [`is_satisfied_by`](Self::is_satisfied_by), for transactions that were signed elsewhere.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if `bytes` isn't a valid transaction.
+    pub fn is_satisfied_by_bytes(&self, bytes: &[u8]) -> crate::Result<KeySatisfaction> {
+        Ok(self.is_satisfied_by_sources(&TransactionSources::from_bytes(bytes)?))
+    }
+
+    fn is_satisfied_by_sources(&self, sources: &TransactionSources) -> KeySatisfaction {
+        self.satisfaction(&mut |key| key.verify_transaction_sources(sources).is_ok())
+    }
+
+    /// Walks the key tree, `is_signed` decides whether a single public key signed.
+    pub(crate) fn satisfaction<F>(&self, is_signed: &mut F) -> KeySatisfaction
+    where
+        F: FnMut(&PublicKey) -> bool,
+    {
+        let mut missing = Vec::new();
+
+        let is_satisfied = self.collect_missing(is_signed, &mut missing);
+
+        if is_satisfied {
+            missing.clear();
+        }
+
+        KeySatisfaction { is_satisfied, missing }
+    }
+
+    fn collect_missing<F>(&self, is_signed: &mut F, missing: &mut Vec<Key>) -> bool
+    where
+        F: FnMut(&PublicKey) -> bool,
+    {
+        match self {
+            Self::Single(key) if is_signed(key) => true,
+            Self::Single(_) | Self::ContractId(_) | Self::DelegateContractId(_) => {
+                missing.push(self.clone());
+                false
+            }
+            Self::KeyList(list) => {
+                // without a threshold every key has to sign.
+                let required = list.threshold.map_or(list.keys.len(), |it| it as usize);
+
+                // the network rejects empty key lists and thresholds of 0, so nothing satisfies them.
+                if required == 0 {
+                    return false;
+                }
+
+                let mut list_missing = Vec::new();
+
+                let signed = list
+                    .keys
+                    .iter()
+                    .filter(|key| key.collect_missing(is_signed, &mut list_missing))
+                    .count();
+
+                if signed >= required {
+                    return true;
+                }
+
+                missing.append(&mut list_missing);
+                false
+            }
+        }
+    }
 }
 
 impl ToProtobuf for Key {
@@ -135,8 +291,14 @@ mod tests {
 
     use crate::protobuf::FromProtobuf;
     use crate::{
+        AccountId,
+        Hbar,
         Key,
+        KeyList,
+        PrivateKey,
         PublicKey,
+        TransactionId,
+        TransferTransaction,
     };
 
     #[test]
@@ -229,4 +391,157 @@ mod tests {
 
         assert_matches!(Key::from_protobuf(key), Err(crate::Error::FromProtobuf(_)));
     }
+
+    #[test]
+    fn satisfaction_thresholds() {
+        let keys: Vec<PublicKey> =
+            (0..4).map(|_| PrivateKey::generate_ed25519().public_key()).collect();
+
+        // a 1-of-2 and a 2-of-2, both of which need to sign.
+        let key = Key::KeyList(KeyList {
+            keys: Vec::from([
+                Key::KeyList(KeyList {
+                    keys: Vec::from([keys[0].into(), keys[1].into()]),
+                    threshold: Some(1),
+                }),
+                Key::KeyList(KeyList {
+                    keys: Vec::from([keys[2].into(), keys[3].into()]),
+                    threshold: None,
+                }),
+            ]),
+            threshold: None,
+        });
+
+        let satisfaction = |signed: &[PublicKey]| key.satisfaction(&mut |it| signed.contains(it));
+
+        let it = satisfaction(&[keys[1], keys[2], keys[3]]);
+        assert!(it.is_satisfied);
+        assert!(it.missing.is_empty());
+
+        let it = satisfaction(&[keys[2]]);
+        assert!(!it.is_satisfied);
+        assert_eq!(it.missing, [keys[0].into(), keys[1].into(), keys[3].into()]);
+    }
+
+    #[test]
+    fn satisfied_by_signatures() {
+        let signer1 = PrivateKey::generate_ed25519();
+        let signer2 = PrivateKey::generate_ecdsa();
+        let other = PrivateKey::generate_ed25519();
+
+        let key = Key::KeyList(KeyList {
+            keys: Vec::from([
+                signer1.public_key().into(),
+                signer2.public_key().into(),
+                other.public_key().into(),
+            ]),
+            threshold: Some(2),
+        });
+
+        let msg = b"hello, world";
+
+        let mut signatures = Vec::from([
+            (signer1.public_key(), signer1.sign(msg)),
+            // signed something else, doesn't count.
+            (other.public_key(), other.sign(b"goodbye, world")),
+        ]);
+
+        let it = key.is_satisfied_by_signatures(msg, &signatures);
+        assert!(!it.is_satisfied);
+        assert_eq!(it.missing, [signer2.public_key().into(), other.public_key().into()]);
+        assert_matches!(
+            key.verify_signatures(msg, &signatures),
+            Err(crate::Error::SignatureVerify(_))
+        );
+
+        signatures.push((signer2.public_key(), signer2.sign(msg)));
+
+        assert!(key.is_satisfied_by_signatures(msg, &signatures).is_satisfied);
+        assert!(key.verify_signatures(msg, &signatures).is_ok());
+    }
+
+    #[test]
+    fn satisfied_by_transaction() -> crate::Result<()> {
+        let signer1 = PrivateKey::generate_ed25519();
+        let signer2 = PrivateKey::generate_ecdsa();
+        let other = PrivateKey::generate_ed25519();
+
+        let key = Key::KeyList(KeyList {
+            keys: Vec::from([
+                signer1.public_key().into(),
+                signer2.public_key().into(),
+                other.public_key().into(),
+            ]),
+            threshold: Some(2),
+        });
+
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(2))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::new(-2))
+            .transaction_id(TransactionId::generate(AccountId::new(0, 0, 101)))
+            .node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)])
+            .freeze()?
+            .sign(signer1);
+
+        let it = key.is_satisfied_by(&tx)?;
+        assert!(!it.is_satisfied);
+        assert_eq!(it.missing, [signer2.public_key().into(), other.public_key().into()]);
+
+        tx.sign(signer2);
+
+        assert!(key.is_satisfied_by(&tx)?.is_satisfied);
+        assert!(key.is_satisfied_by_bytes(&tx.to_bytes()?)?.is_satisfied);
+
+        Ok(())
+    }
+
+    #[test]
+    fn satisfied_by_bytes() -> crate::Result<()> {
+        let signer = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ecdsa();
+
+        let key = Key::KeyList(KeyList {
+            keys: Vec::from([signer.public_key().into(), other.public_key().into()]),
+            threshold: None,
+        });
+
+        let bytes = TransferTransaction::new()
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(2))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::new(-2))
+            .transaction_id(TransactionId::generate(AccountId::new(0, 0, 101)))
+            .node_account_ids([AccountId::new(0, 0, 3)])
+            .freeze()?
+            .sign(signer)
+            .to_bytes()?;
+
+        let it = key.is_satisfied_by_bytes(&bytes)?;
+        assert!(!it.is_satisfied);
+        assert_eq!(it.missing, [other.public_key().into()]);
+
+        assert_matches!(key.is_satisfied_by_bytes(b"not a transaction"), Err(_));
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_key_list_is_unsatisfiable() {
+        let signer = PrivateKey::generate_ed25519().public_key();
+        let mut signed = |it: &PublicKey| *it == signer;
+
+        let empty = Key::KeyList(KeyList { keys: Vec::new(), threshold: None });
+        let it = empty.satisfaction(&mut signed);
+        assert!(!it.is_satisfied);
+        assert!(it.missing.is_empty());
+
+        let zero_threshold =
+            Key::KeyList(KeyList { keys: Vec::from([signer.into()]), threshold: Some(0) });
+        assert!(!zero_threshold.satisfaction(&mut signed).is_satisfied);
+
+        // they only count as unsigned keys inside of other key lists.
+        let nested = Key::KeyList(KeyList {
+            keys: Vec::from([signer.into(), empty, zero_threshold]),
+            threshold: Some(1),
+        });
+        assert!(nested.satisfaction(&mut signed).is_satisfied);
+    }
 }
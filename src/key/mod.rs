@@ -18,16 +18,28 @@
  * ‍
  */
 
+mod bip32;
+mod derivation_path;
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
 mod private_key;
 mod public_key;
 
-pub use key::Key;
+pub use derivation_path::DerivationPath;
+pub use key::{
+    Key,
+    KeySatisfaction,
+};
 pub use key_list::KeyList;
-pub use private_key::PrivateKey;
-pub use public_key::PublicKey;
+pub use private_key::{
+    PrivateKey,
+    RedactedPrivateKey,
+};
+pub use public_key::{
+    ExtendedPublicKey,
+    PublicKey,
+};
 
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum KeyKind {
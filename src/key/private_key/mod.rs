@@ -52,11 +52,19 @@ use sec1::EcPrivateKey;
 use sha2::Sha512;
 use sha3::Digest;
 use triomphe::Arc;
+use zeroize::{
+    Zeroize,
+    Zeroizing,
+};
 
+use super::bip32;
+use crate::key::public_key::EC_ALGORITM_OID;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    DerivationPath,
     Error,
+    ExtendedPublicKey,
     PublicKey,
     Transaction,
 };
@@ -101,15 +109,27 @@ pub struct PrivateKey(Arc<PrivateKeyDataWrapper>);
 struct PrivateKeyDataWrapper {
     data: PrivateKeyData,
     chain_code: Option<[u8; 32]>,
+    origin: bip32::KeyOrigin,
 }
 
 impl PrivateKeyDataWrapper {
     fn new(inner: PrivateKeyData) -> Self {
-        Self { data: inner, chain_code: None }
+        Self { data: inner, chain_code: None, origin: bip32::KeyOrigin::default() }
     }
 
     fn new_derivable(inner: PrivateKeyData, chain_code: [u8; 32]) -> Self {
-        Self { data: inner, chain_code: Some(chain_code) }
+        Self::new_derived(inner, chain_code, bip32::KeyOrigin::default())
+    }
+
+    fn new_derived(inner: PrivateKeyData, chain_code: [u8; 32], origin: bip32::KeyOrigin) -> Self {
+        Self { data: inner, chain_code: Some(chain_code), origin }
+    }
+}
+
+// the keys themselves are zeroized by their own `Drop` impls.
+impl Drop for PrivateKeyDataWrapper {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
     }
 }
 
@@ -161,6 +181,10 @@ impl PrivateKey {
         Self::new(PrivateKeyDataWrapper::new_derivable(key, chain_code))
     }
 
+    fn new_derived(key: PrivateKeyData, chain_code: [u8; 32], origin: bip32::KeyOrigin) -> Self {
+        Self::new(PrivateKeyDataWrapper::new_derived(key, chain_code, origin))
+    }
+
     fn ed25519(key: ed25519_dalek::SigningKey) -> Self {
         Self::new(key.into())
     }
@@ -255,6 +279,16 @@ impl PrivateKey {
             Err(e) => return Self::from_sec1_bytes_der(bytes).ok().ok_or(e),
         };
 
+        // the standard (RFC 5915) encoding of EC keys, where the `PrivateKey` is a SEC1 `ECPrivateKey`,
+        // and the curve is given by the algorithm's parameters.
+        if info.algorithm.oid == EC_ALGORITM_OID {
+            return match info.algorithm.parameters_oid().ok() {
+                Some(K256_OID) => Self::from_sec1_bytes_der_with_curve(info.private_key, K256_OID),
+                Some(oid) => Err(Error::key_parse(format!("unsupported curve OID: {oid}"))),
+                None => Err(Error::key_parse("missing curve parameters")),
+            };
+        }
+
         // PrivateKey is an `OctetString`, and the `PrivateKey`s we all support are `OctetStrings`.
         // So, we, awkwardly, have an `OctetString` containing an `OctetString` containing our key material.
         let inner = pkcs8::der::asn1::OctetStringRef::from_der(info.private_key)
@@ -279,6 +313,21 @@ impl PrivateKey {
         }
     }
 
+    /// Like [`from_sec1_bytes_der`](Self::from_sec1_bytes_der), but the curve parameters are optional.
+    fn from_sec1_bytes_der_with_curve(
+        bytes: &[u8],
+        curve: ObjectIdentifier,
+    ) -> crate::Result<Self> {
+        let sec1 = EcPrivateKey::try_from(bytes).map_err(Error::key_parse)?;
+
+        match sec1.parameters.and_then(sec1::EcParameters::named_curve) {
+            Some(oid) if oid != curve => Err(Error::key_parse(format!(
+                "curve OID `{oid}` doesn't match the algorithm's curve OID `{curve}`"
+            ))),
+            _ => Self::from_bytes_ecdsa(sec1.private_key),
+        }
+    }
+
     /// Parse a `PrivateKey` from a der encoded string.
     ///
     /// Optionally strips a `0x` prefix.
@@ -463,13 +512,15 @@ impl PrivateKey {
     }
 
     /// Return this `PrivateKey`, serialized as der encoded bytes.
+    ///
+    /// The bytes are zeroized when dropped.
     // panic should be impossible (`unreachable`)
     #[allow(clippy::missing_panics_doc)]
     #[must_use]
-    pub fn to_bytes_der(&self) -> Vec<u8> {
-        let mut inner = Vec::with_capacity(34);
+    pub fn to_bytes_der(&self) -> Zeroizing<Vec<u8>> {
+        let mut inner = Zeroizing::new(Vec::with_capacity(34));
 
-        pkcs8::der::asn1::OctetStringRef::new(&self.to_bytes_raw_internal())
+        pkcs8::der::asn1::OctetStringRef::new(&*self.to_bytes_raw_internal())
             .unwrap()
             .encode_to_vec(&mut inner)
             .unwrap();
@@ -480,18 +531,106 @@ impl PrivateKey {
             public_key: None,
         };
 
-        let mut buf = Vec::with_capacity(64);
+        let mut buf = Zeroizing::new(Vec::with_capacity(64));
         info.encode_to_vec(&mut buf).unwrap();
 
         buf
     }
 
+    /// Return this `PrivateKey` as PKCS#8 der, in the form that other tools (such as OpenSSL) expect.
+    ///
+    /// This is the same as [`to_bytes_der`](Self::to_bytes_der) for Ed25519 keys,
+    /// but ECDSA(secp256k1) keys are encoded as a SEC1 `ECPrivateKey` (RFC 5915)
+    /// rather than the raw key material Hedera uses.
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    fn to_bytes_pkcs8(&self) -> Zeroizing<Vec<u8>> {
+        let PrivateKeyData::Ecdsa(key) = &self.0.data else {
+            return self.to_bytes_der();
+        };
+
+        let private_key = self.to_bytes_raw_internal();
+        let public_key = key.verifying_key().to_encoded_point(false);
+
+        let mut inner = Zeroizing::new(Vec::with_capacity(118));
+
+        EcPrivateKey {
+            private_key: private_key.as_slice(),
+            parameters: None,
+            public_key: Some(public_key.as_bytes()),
+        }
+        .encode_to_vec(&mut inner)
+        .unwrap();
+
+        let info = pkcs8::PrivateKeyInfo {
+            algorithm: pkcs8::AlgorithmIdentifierRef {
+                oid: EC_ALGORITM_OID,
+                parameters: Some((&K256_OID).into()),
+            },
+            private_key: &inner,
+            public_key: None,
+        };
+
+        let mut buf = Zeroizing::new(Vec::with_capacity(144));
+        info.encode_to_vec(&mut buf).unwrap();
+
+        buf
+    }
+
+    /// Return this `PrivateKey`, serialized as [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-10) encoded PKCS#8.
+    ///
+    /// ECDSA(secp256k1) keys are encoded the standard (RFC 5915) way, so that tools such as OpenSSL can read them,
+    /// [`from_pem`](Self::from_pem) can read either form.
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        let pem = ::pem::Pem::new("PRIVATE KEY", self.to_bytes_pkcs8().as_slice());
+
+        ::pem::encode_config(
+            &pem,
+            ::pem::EncodeConfig::new().set_line_ending(::pem::LineEnding::LF),
+        )
+    }
+
+    /// Return this `PrivateKey`, serialized as encrypted [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-11)
+    /// encoded PKCS#8, which can be read with [`from_pem_with_password`](Self::from_pem_with_password).
+    ///
+    /// The key is encrypted with PBES2, using scrypt (`N = 2^14, r = 8, p = 1`, the same as `openssl pkcs8 -scrypt`)
+    /// to derive an AES-256-CBC key from `password`, with a random salt and IV.
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn to_pem_with_password(&self, password: impl AsRef<[u8]>) -> String {
+        use rand::Rng as _;
+
+        let mut csprng = rand::thread_rng();
+
+        let salt: [u8; 16] = csprng.gen();
+        let iv: [u8; 16] = csprng.gen();
+
+        let scrypt = pkcs8::pkcs5::scrypt::Params::new(14, 8, 1, 32).unwrap();
+        let params = pkcs8::pkcs5::pbes2::Parameters::scrypt_aes256cbc(scrypt, &salt, &iv).unwrap();
+
+        let der = self.to_bytes_pkcs8();
+        let info = pkcs8::PrivateKeyInfo::from_der(&der).unwrap();
+
+        let encrypted = info.encrypt_with_params(params, password).unwrap();
+
+        let pem = ::pem::Pem::new("ENCRYPTED PRIVATE KEY", encrypted.as_bytes());
+
+        ::pem::encode_config(
+            &pem,
+            ::pem::EncodeConfig::new().set_line_ending(::pem::LineEnding::LF),
+        )
+    }
+
     /// Return this `PrivateKey`, serialized as bytes.
     ///
     /// If this is an ed25519 private key, this is equivalent to [`to_bytes_raw`](Self::to_bytes_raw)
     /// If this is an ecdsa private key, this is equivalent to [`to_bytes_der`](Self::to_bytes_der)
+    ///
+    /// The bytes are zeroized when dropped.
     #[must_use]
-    pub fn to_bytes(&self) -> Vec<u8> {
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
         match &self.0.data {
             PrivateKeyData::Ed25519(_) => self.to_bytes_raw(),
             PrivateKeyData::Ecdsa(_) => self.to_bytes_der(),
@@ -499,17 +638,19 @@ impl PrivateKey {
     }
 
     /// Return this `PrivateKey`, serialized as bytes.
+    ///
+    /// The bytes are zeroized when dropped.
     #[must_use]
-    pub fn to_bytes_raw(&self) -> Vec<u8> {
-        self.to_bytes_raw_internal().as_slice().to_vec()
+    pub fn to_bytes_raw(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.to_bytes_raw_internal().to_vec())
     }
 
     #[must_use]
-    fn to_bytes_raw_internal(&self) -> [u8; 32] {
-        match &self.0.data {
+    fn to_bytes_raw_internal(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(match &self.0.data {
             PrivateKeyData::Ed25519(key) => key.to_bytes(),
             PrivateKeyData::Ecdsa(key) => key.to_bytes().into(),
-        }
+        })
     }
 
     /// DER encodes self, then hex encodes the result.
@@ -521,7 +662,15 @@ impl PrivateKey {
     /// Returns the raw bytes of `self` after hex encoding.
     #[must_use]
     pub fn to_string_raw(&self) -> String {
-        hex::encode(self.to_bytes_raw_internal())
+        hex::encode(&*self.to_bytes_raw_internal())
+    }
+
+    /// Returns a wrapper that formats `self` as only its public key, without revealing the private key.
+    ///
+    /// Useful for keys that could otherwise end up in logs.
+    #[must_use]
+    pub fn redacted(&self) -> RedactedPrivateKey<'_> {
+        RedactedPrivateKey(self)
     }
 
     /// Creates an [`AccountId`] with the given `shard`, `realm`, and `self.public_key()` as an [`alias`](AccountId::alias).
@@ -593,6 +742,9 @@ impl PrivateKey {
     }
 
     /// Signs the given `message`.
+    ///
+    /// ECDSA(secp256k1) signatures are over the keccak256 hash of `message`,
+    /// they're deterministic ([RFC 6979](https://www.rfc-editor.org/rfc/rfc6979)) and always have a low `s` value.
     #[must_use]
     pub fn sign(&self, message: &[u8]) -> Vec<u8> {
         match &self.0.data {
@@ -606,6 +758,57 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the given `message`, returning a recoverable signature (`r || s || v`).
+    ///
+    /// The signature is the same one [`sign`](Self::sign) returns, followed by the recovery ID `v` (`0` or `1`),
+    /// so the public key can be recovered with [`PublicKey::recover_from_signature`].
+    /// Note that Ethereum's `personal_sign` expects `v` to be `27` or `28`.
+    ///
+    /// Returns `None` if this is an Ed25519 key, Ed25519 signatures aren't recoverable.
+    // panic should be impossible (the same as for `sign`).
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn sign_recoverable(&self, message: &[u8]) -> Option<[u8; 65]> {
+        let PrivateKeyData::Ecdsa(key) = &self.0.data else {
+            return None;
+        };
+
+        let (signature, recovery_id) = key
+            .sign_digest_recoverable(sha3::Keccak256::new_with_prefix(message))
+            .expect("signing should be infallible for valid keys");
+
+        let mut out = [0; 65];
+        out[..64].copy_from_slice(&signature.to_bytes());
+        out[64] = recovery_id.to_byte();
+
+        Some(out)
+    }
+
+    /// Signs `message` as an Ethereum `personal_sign` message ([EIP-191](https://eips.ethereum.org/EIPS/eip-191) version `0x45`).
+    ///
+    /// Returns a recoverable signature (`r || s || v`) where `v` is `27` or `28`,
+    /// or `None` if this is an Ed25519 key.
+    #[must_use]
+    pub fn sign_personal_message(&self, message: &[u8]) -> Option<[u8; 65]> {
+        self.sign_eip191(&crate::ethereum::eip191::personal_message(message))
+    }
+
+    /// Signs EIP-712 typed structured data, as `eth_signTypedData_v4` does.
+    ///
+    /// Returns a recoverable signature (`r || s || v`) where `v` is `27` or `28`,
+    /// or `None` if this is an Ed25519 key.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn sign_typed_data(&self, typed_data: &crate::TypedData) -> Option<[u8; 65]> {
+        self.sign_eip191(&typed_data.to_signed_data())
+    }
+
+    fn sign_eip191(&self, data: &[u8]) -> Option<[u8; 65]> {
+        let mut signature = self.sign_recoverable(data)?;
+        signature[64] += 27;
+        Some(signature)
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     ///
@@ -618,27 +821,43 @@ impl PrivateKey {
     ) -> crate::Result<Vec<u8>> {
         transaction.freeze()?;
 
-        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()));
-
-        Ok(sig)
+        transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()))
     }
 
     /// Returns true if calling [`derive`](Self::derive) on `self` would succeed.
     #[must_use]
     pub fn is_derivable(&self) -> bool {
-        self.is_ed25519() && self.0.chain_code.is_some()
+        self.0.chain_code.is_some()
+    }
+
+    /// Returns the chain code of this key, if it's [derivable](Self::is_derivable).
+    ///
+    /// The chain code is zeroized when dropped.
+    #[must_use]
+    pub fn chain_code(&self) -> Option<Zeroizing<[u8; 32]>> {
+        self.0.chain_code.map(Zeroizing::new)
+    }
+
+    /// Returns how many times this key has been [derived](Self::derive) from its master key.
+    ///
+    /// Master keys (and keys that aren't derivable at all) have a depth of `0`.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        self.0.origin.depth
     }
 
     /// Derives a child key based on `index`.
     ///
+    /// Ed25519 keys are always derived as if `index` was hardened (SLIP-10),
+    /// ECDSA(secp256k1) keys follow BIP-32, where `index` is hardened if its highest bit is set (IE: it's negative).
+    ///
     /// # Errors
-    /// - [`Error::KeyDerive`] if this is an Ecdsa key (unsupported operation)
     /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if the derived ECDSA key is invalid (astronomically unlikely, use the next index)
     // this is specifically for the two `try_into`s which depend on `split_array_ref`.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[allow(clippy::missing_panics_doc)]
     pub fn derive(&self, index: i32) -> crate::Result<Self> {
-        const HARDEND_MASK: u32 = 1 << 31;
         let index = index as u32;
 
         let chain_code =
@@ -647,36 +866,127 @@ impl PrivateKey {
         match &self.0.data {
             PrivateKeyData::Ed25519(key) => {
                 // force hardened.
-                let index = index | HARDEND_MASK;
+                let index = index | bip32::HARDENED;
+
+                // SLIP-10 fingerprints Ed25519 keys as `0x00 || public key`.
+                let mut parent = [0; 33];
+                parent[1..].copy_from_slice(key.verifying_key().as_bytes());
 
-                let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(chain_code)
-                    .expect("HMAC can take keys of any size")
-                    .chain_update([0])
-                    .chain_update(key.to_bytes())
-                    .chain_update(index.to_be_bytes())
-                    .finalize()
-                    .into_bytes()
-                    .into();
+                let origin = self.0.origin.child(&parent, index)?;
+
+                let output: Zeroizing<[u8; 64]> = Zeroizing::new(
+                    Hmac::<Sha512>::new_from_slice(chain_code)
+                        .expect("HMAC can take keys of any size")
+                        .chain_update([0])
+                        .chain_update(key.to_bytes())
+                        .chain_update(index.to_be_bytes())
+                        .finalize()
+                        .into_bytes()
+                        .into(),
+                );
 
                 // todo: use `split_array_ref` when that's stable.
                 let (data, chain_code) = split_key_array(&output);
 
                 let data = ed25519_dalek::SigningKey::from_bytes(data);
 
-                Ok(Self::new_derivable(data.into(), *chain_code))
+                Ok(Self::new_derived(data.into(), *chain_code, origin))
             }
-            PrivateKeyData::Ecdsa(_) => {
-                Err(Error::key_derive("Ecdsa private keys don't currently support derivation"))
+            PrivateKeyData::Ecdsa(key) => {
+                let parent = key.verifying_key().to_encoded_point(true);
+                let origin = self.0.origin.child(parent.as_bytes(), index)?;
+
+                let (data, chain_code) = bip32::derive_private(key, chain_code, index)?;
+
+                Ok(Self::new_derived(data.into(), chain_code, origin))
             }
         }
     }
 
+    /// Derives the key at `path`, relative to this key, by [deriving](Self::derive) each index in turn.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key and `path` has a non-hardened index,
+    ///   Ed25519 keys only support hardened derivation.
+    /// - [`Error::KeyDerive`] if a derived ECDSA key is invalid (astronomically unlikely)
+    pub fn derive_path(&self, path: &DerivationPath) -> crate::Result<Self> {
+        if self.is_ed25519() && path.indexes().iter().any(|it| it & bip32::HARDENED == 0) {
+            return Err(Error::key_derive(format!(
+                "Ed25519 keys only support hardened derivation, but the path `{path}` has non-hardened indexes"
+            )));
+        }
+
+        path.indexes().iter().try_fold(self.clone(), |key, &index| key.derive(index as i32))
+    }
+
+    /// Returns the [`ExtendedPublicKey`] of this key, which can derive the public keys of non-hardened children.
+    ///
+    /// Returns `None` unless this is a derivable ECDSA(secp256k1) key,
+    /// Ed25519 keys only support hardened derivation, so there's nothing to derive from their public keys.
+    #[must_use]
+    pub fn extended_public_key(&self) -> Option<ExtendedPublicKey> {
+        match (&self.0.data, self.0.chain_code) {
+            (PrivateKeyData::Ecdsa(_), Some(chain_code)) => {
+                Some(ExtendedPublicKey::new(self.public_key(), chain_code, self.0.origin))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns this key serialized as a Base58Check extended private key (`xprv...`).
+    ///
+    /// Returns `None` unless this is a derivable ECDSA(secp256k1) key,
+    /// there's no standard extended key serialization for Ed25519 keys.
+    #[must_use]
+    pub fn to_string_xprv(&self) -> Option<String> {
+        let (PrivateKeyData::Ecdsa(key), Some(chain_code)) = (&self.0.data, &self.0.chain_code)
+        else {
+            return None;
+        };
+
+        let mut data = Zeroizing::new([0; 33]);
+        data[1..].copy_from_slice(&key.to_bytes());
+
+        Some(bip32::encode(bip32::XPRV, &self.0.origin, chain_code, &data))
+    }
+
+    /// Parse a derivable ECDSA(secp256k1) `PrivateKey` from a Base58Check extended private key (`xprv...`).
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `s` isn't a valid mainnet extended private key.
+    pub fn from_str_xprv(s: &str) -> crate::Result<Self> {
+        let (origin, chain_code, data) = bip32::decode(s, bip32::XPRV)?;
+
+        if data[0] != 0 {
+            return Err(Error::key_parse("extended private key data must start with `0x00`"));
+        }
+
+        let data =
+            k256::ecdsa::SigningKey::from_bytes(data[1..].into()).map_err(Error::key_parse)?;
+
+        Ok(Self::new_derived(data.into(), chain_code, origin))
+    }
+
+    /// Returns the BIP-32 master ECDSA(secp256k1) key for `seed`, which can be [derived](Self::derive) from.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if the master key for `seed` is invalid (astronomically unlikely).
+    pub fn from_seed_ecdsa(seed: &[u8]) -> crate::Result<Self> {
+        let (data, chain_code) = bip32::master_key(seed)?;
+
+        Ok(Self::new_derivable(data.into(), chain_code))
+    }
+
     // todo: what do we do about i32?
     // It's basically just a cast to support them, but, unlike Java, operator overloading doesn't exist.
     /// Derive a `PrivateKey` based on `index`.
     ///
+    /// This is the derivation scheme of legacy (pre BIP-39) Hedera wallets, which only ever had Ed25519 keys,
+    /// so there's no legacy derivation for ECDSA(secp256k1) keys, use [`derive`](Self::derive) for those.
+    ///
     /// # Errors
-    /// - [`Error::KeyDerive`] if this is an Ecdsa key (unsupported operation)
+    /// - [`Error::KeyDerive`] if this is an ECDSA key (unsupported operation)
     // ⚠️ unaudited cryptography ⚠️
     pub fn legacy_derive(&self, index: i64) -> crate::Result<Self> {
         match &self.0.data {
@@ -698,30 +1008,33 @@ impl PrivateKey {
                 // any better way to do this?
                 seed.extend_from_slice(&[i2; 4]);
 
-                let mat = pbkdf2::pbkdf2_hmac_array::<Sha512, 32>(&seed, &[0xff], 2048);
+                let mat =
+                    Zeroizing::new(pbkdf2::pbkdf2_hmac_array::<Sha512, 32>(&seed, &[0xff], 2048));
 
                 Ok(Self::ed25519(ed25519_dalek::SigningKey::from_bytes(&mat)))
             }
 
             PrivateKeyData::Ecdsa(_) => {
-                Err(Error::key_derive("Ecdsa private keys don't currently support derivation"))
+                Err(Error::key_derive("Ecdsa private keys don't support legacy derivation"))
             }
         }
     }
 
     #[cfg(feature = "mnemonic")]
     pub(crate) fn from_mnemonic_seed(seed: &[u8]) -> Self {
-        let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
-            .expect("hmac can take a seed of any size")
-            .chain_update(seed)
-            .finalize()
-            .into_bytes()
-            .into();
+        let output: Zeroizing<[u8; 64]> = Zeroizing::new(
+            Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
+                .expect("hmac can take a seed of any size")
+                .chain_update(seed)
+                .finalize()
+                .into_bytes()
+                .into(),
+        );
 
         // todo: use `split_array_ref` when that's stable.
         let (left, right) = {
             let (left, right) = output.split_at(32);
-            let left: [u8; 32] = left.try_into().unwrap();
+            let left: Zeroizing<[u8; 32]> = Zeroizing::new(left.try_into().unwrap());
             let right: [u8; 32] = right.try_into().unwrap();
             (left, right)
         };
@@ -738,6 +1051,12 @@ impl PrivateKey {
     }
 
     /// Recover a `PrivateKey` from a mnemonic phrase and a passphrase.
+    ///
+    /// The key is always an Ed25519 key derived along `m/44'/3030'/0'/0'` (SLIP-10) and keeps its chain code.
+    /// For ECDSA(secp256k1) keys use [`Mnemonic::to_standard_ecdsa_private_key`]
+    /// or [`from_seed_ecdsa`](Self::from_seed_ecdsa), which keep the BIP-32 chain code the same way.
+    ///
+    /// [`Mnemonic::to_standard_ecdsa_private_key`]: crate::Mnemonic::to_standard_ecdsa_private_key
     // this is specifically for the two `try_into`s which depend on `split_array_ref`.
     // There *is* a 3rd unwrap for a "key is not derivable" error, but we construct a key that _is_ derivable.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
@@ -746,7 +1065,7 @@ impl PrivateKey {
     #[must_use]
     pub fn from_mnemonic(mnemonic: &crate::Mnemonic, passphrase: &str) -> Self {
         let seed = mnemonic.to_seed(passphrase);
-        Self::from_mnemonic_seed(&seed)
+        Self::from_mnemonic_seed(&*seed)
     }
 
     #[must_use]
@@ -770,6 +1089,22 @@ impl Display for PrivateKey {
     }
 }
 
+/// A [`PrivateKey`] that's formatted as only its public key, see [`PrivateKey::redacted`].
+#[derive(Clone, Copy)]
+pub struct RedactedPrivateKey<'a>(&'a PrivateKey);
+
+impl Debug for RedactedPrivateKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+impl Display for RedactedPrivateKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("<redacted private key for {}>", self.0.public_key()))
+    }
+}
+
 impl FromStr for PrivateKey {
     type Err = Error;
 
@@ -777,6 +1112,3 @@ impl FromStr for PrivateKey {
         Self::from_bytes(&hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::key_parse)?)
     }
 }
-
-// TODO: derive (!) - secp256k1
-// TODO: legacy_derive (!) - secp256k1
@@ -0,0 +1,159 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt::{
+    self,
+    Debug,
+    Display,
+    Formatter,
+};
+use std::str::FromStr;
+
+use super::{
+    PublicKey,
+    PublicKeyData,
+};
+use crate::key::bip32;
+use crate::{
+    DerivationPath,
+    Error,
+};
+
+/// A [`PublicKey`] together with a chain code,
+/// from which the public keys of non-hardened child keys can be derived without the private key (BIP-32).
+///
+/// Only ECDSA(secp256k1) keys support this.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtendedPublicKey {
+    public_key: PublicKey,
+    chain_code: [u8; 32],
+    origin: bip32::KeyOrigin,
+}
+
+impl ExtendedPublicKey {
+    pub(crate) fn new(
+        public_key: PublicKey,
+        chain_code: [u8; 32],
+        origin: bip32::KeyOrigin,
+    ) -> Self {
+        Self { public_key, chain_code, origin }
+    }
+
+    /// Returns the public key.
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Returns the chain code.
+    #[must_use]
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    /// Returns how many times this key has been derived from its master key.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        self.origin.depth
+    }
+
+    /// Parse an `ExtendedPublicKey` from a Base58Check extended public key (`xpub...`).
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `s` isn't a valid mainnet extended public key.
+    pub fn from_str_xpub(s: &str) -> crate::Result<Self> {
+        let (origin, chain_code, data) = bip32::decode(s, bip32::XPUB)?;
+
+        if !matches!(data[0], 0x02 | 0x03) {
+            return Err(Error::key_parse("extended public key must be a compressed point"));
+        }
+
+        let key =
+            k256::ecdsa::VerifyingKey::from_sec1_bytes(&data[..]).map_err(Error::key_parse)?;
+
+        Ok(Self::new(PublicKey::ecdsa(key), chain_code, origin))
+    }
+
+    /// Returns this key serialized as a Base58Check extended public key (`xpub...`).
+    #[must_use]
+    pub fn to_string_xpub(&self) -> String {
+        let PublicKeyData::Ecdsa(key) = &self.public_key.0 else {
+            unreachable!("extended public keys are always ECDSA(secp256k1) keys")
+        };
+
+        let data: [u8; 33] = key.to_encoded_point(true).as_bytes().try_into().unwrap();
+
+        bip32::encode(bip32::XPUB, &self.origin, &self.chain_code, &data)
+    }
+
+    /// Derives the extended public key of the child key at `index`,
+    /// which has the same public key as [`PrivateKey::derive`](crate::PrivateKey::derive) would for the private key.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `index` is hardened (IE: negative), hardened keys can only be derived from private keys.
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key, those only support hardened derivation.
+    /// - [`Error::KeyDerive`] if the derived key is invalid (astronomically unlikely, use the next index)
+    pub fn derive(&self, index: i32) -> crate::Result<Self> {
+        let PublicKeyData::Ecdsa(key) = &self.public_key.0 else {
+            return Err(Error::key_derive("Ed25519 public keys can't be derived from"));
+        };
+
+        let index = index as u32;
+
+        let origin = self.origin.child(key.to_encoded_point(true).as_bytes(), index)?;
+
+        let (key, chain_code) = bip32::derive_public(key, &self.chain_code, index)?;
+
+        Ok(Self::new(PublicKey::ecdsa(key), chain_code, origin))
+    }
+
+    /// Derives the extended public key at `path`, relative to this key, by [deriving](Self::derive) each index in turn.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `path` has a hardened index.
+    /// - [`Error::KeyDerive`] if a derived key is invalid (astronomically unlikely)
+    pub fn derive_path(&self, path: &DerivationPath) -> crate::Result<Self> {
+        path.indexes().iter().try_fold(*self, |key, &index| key.derive(index as i32))
+    }
+}
+
+impl Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad(&self.to_string_xpub())
+    }
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_xpub(s)
+    }
+}
+
+impl Debug for ExtendedPublicKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPublicKey")
+            .field("public_key", &self.public_key)
+            .field("chain_code", &hex::encode(self.chain_code))
+            .field("depth", &self.origin.depth)
+            .finish()
+    }
+}
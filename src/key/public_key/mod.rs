@@ -60,9 +60,12 @@ use crate::{
     Transaction,
 };
 
+mod extended;
 #[cfg(test)]
 mod tests;
 
+pub use extended::ExtendedPublicKey;
+
 pub(super) const EC_ALGORITM_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
 
@@ -370,6 +373,80 @@ impl PublicKey {
         }
     }
 
+    /// Recover the ECDSA(secp256k1) public key that created a recoverable `signature` (`r || s || v`) of `msg`.
+    ///
+    /// `msg` is hashed with keccak256, like [`PrivateKey::sign_recoverable`](crate::PrivateKey::sign_recoverable) does.
+    /// `v` can either be the recovery ID (`0` or `1`) or Ethereum's `27` or `28`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature isn't 65 bytes long or has an invalid `v`.
+    /// - [`Error::SignatureVerify`] if the signature has a high `s` value, or no public key can be recovered from it.
+    pub fn recover_from_signature(msg: &[u8], signature: &[u8]) -> crate::Result<Self> {
+        let (&v, signature) = match signature.split_last() {
+            Some((v, signature)) if signature.len() == 64 => (v, signature),
+            _ => {
+                return Err(Error::signature_verify(format!(
+                    "recoverable signatures must be 65 bytes long, but this one is {} bytes long",
+                    signature.len()
+                )))
+            }
+        };
+
+        let recovery_id = ecdsa::RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })
+            .filter(|it| !it.is_x_reduced())
+            .ok_or_else(|| {
+                Error::signature_verify(format!(
+                    "invalid recovery ID `{v}`, expected 0, 1, 27 or 28"
+                ))
+            })?;
+
+        let signature = ecdsa::Signature::try_from(signature).map_err(Error::signature_verify)?;
+
+        // checked explicitly so that the malleable twin of a signature isn't accepted.
+        if signature.normalize_s().is_some() {
+            return Err(Error::signature_verify("signature has a high `s` value"));
+        }
+
+        ecdsa::VerifyingKey::recover_from_digest(
+            sha3::Keccak256::new_with_prefix(msg),
+            &signature,
+            recovery_id,
+        )
+        .map(Self::ecdsa)
+        .map_err(Error::signature_verify)
+    }
+
+    /// Verify a recoverable `signature` of an Ethereum `personal_sign` message,
+    /// see [`PrivateKey::sign_personal_message`](crate::PrivateKey::sign_personal_message).
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is invalid, or wasn't made by this `PublicKey`.
+    pub fn verify_personal_message(&self, message: &[u8], signature: &[u8]) -> crate::Result<()> {
+        self.verify_eip191(&crate::ethereum::eip191::personal_message(message), signature)
+    }
+
+    /// Verify a recoverable `signature` of EIP-712 typed structured data,
+    /// see [`PrivateKey::sign_typed_data`](crate::PrivateKey::sign_typed_data).
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature is invalid, or wasn't made by this `PublicKey`.
+    #[cfg(feature = "serde")]
+    pub fn verify_typed_data(
+        &self,
+        typed_data: &crate::TypedData,
+        signature: &[u8],
+    ) -> crate::Result<()> {
+        self.verify_eip191(&typed_data.to_signed_data(), signature)
+    }
+
+    fn verify_eip191(&self, data: &[u8], signature: &[u8]) -> crate::Result<()> {
+        if Self::recover_from_signature(data, signature)? != *self {
+            return Err(Error::signature_verify("signature was made by a different key"));
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn verify_transaction_sources(
         &self,
         sources: &TransactionSources,
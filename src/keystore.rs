@@ -0,0 +1,617 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use aes::cipher::{
+    KeyIvInit,
+    StreamCipher,
+};
+use rand::Rng;
+use sha3::{
+    Digest,
+    Keccak256,
+};
+use zeroize::Zeroizing;
+
+use crate::{
+    AccountId,
+    Error,
+    LedgerId,
+    PrivateKey,
+};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// The scrypt cost (`log2(N)`, `p`) that geth uses by default.
+const STANDARD_SCRYPT: (u8, u32) = (18, 1);
+
+/// The scrypt cost (`log2(N)`, `p`) that geth uses with `--lightkdf`.
+const LIGHT_SCRYPT: (u8, u32) = (12, 6);
+
+/// The largest scrypt `log2(N)` that will be loaded, with `r = 8` this uses 1 GiB of memory.
+const MAX_SCRYPT_LOG_N: u32 = 20;
+
+/// The largest scrypt block size (`r`) that will be loaded.
+const MAX_SCRYPT_R: u32 = 8;
+
+/// The largest scrypt parallelization (`p`) that will be loaded.
+const MAX_SCRYPT_P: u32 = 16;
+
+/// The largest number of PBKDF2 rounds (`c`) that will be loaded, geth uses `2^18`.
+const MAX_PBKDF2_C: u32 = 1 << 22;
+
+/// A key in a [`Keystore`], along with what it's for.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct KeystoreEntry {
+    /// The private key.
+    pub private_key: PrivateKey,
+
+    /// The account that the key belongs to.
+    pub account_id: Option<AccountId>,
+
+    /// The ledger that `account_id` is on.
+    pub ledger_id: Option<LedgerId>,
+
+    /// A name for the key, such as `operator`.
+    pub label: Option<String>,
+}
+
+impl KeystoreEntry {
+    /// Create a new `KeystoreEntry` for `private_key`, without any metadata.
+    #[must_use]
+    pub fn new(private_key: PrivateKey) -> Self {
+        Self { private_key, account_id: None, ledger_id: None, label: None }
+    }
+}
+
+/// Password protected storage for [`PrivateKey`]s and what they're for,
+/// in the Ethereum (Web3 Secret Storage v3) JSON keystore format.
+///
+/// Every key is encrypted with AES-128-CTR, using a key derived from the password with scrypt,
+/// and authenticated with a Keccak-256 MAC.
+/// Hedera specific metadata (`keyType`, `accountId`, `ledgerId`, and `label`) is stored alongside each key.
+///
+/// A keystore with a single key is saved as a plain keystore object, so that other tools can import it,
+/// otherwise it's saved as an array of them.
+/// Plain Ethereum keystores (which hold ECDSA(secp256k1) keys) can be loaded too.
+///
+/// # Examples
+/// ```
+/// use hedera::{Keystore, KeystoreEntry, PrivateKey};
+///
+/// let mut entry = KeystoreEntry::new(PrivateKey::generate_ed25519());
+/// entry.label = Some("operator".to_owned());
+///
+/// let mut keystore = Keystore::new();
+/// keystore.set_light_kdf(true).add(entry);
+///
+/// let json = keystore.to_json("hunter2");
+///
+/// let keystore = Keystore::from_json(&json, "hunter2")?;
+/// assert!(keystore.get("operator").is_some());
+/// # Ok::<(), hedera::Error>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Keystore {
+    entries: Vec<KeystoreEntry>,
+    light_kdf: bool,
+}
+
+impl Keystore {
+    /// Create a new, empty, `Keystore`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the entries in this keystore.
+    #[must_use]
+    pub fn entries(&self) -> &[KeystoreEntry] {
+        &self.entries
+    }
+
+    /// Adds an entry to this keystore.
+    pub fn add(&mut self, entry: KeystoreEntry) -> &mut Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Returns the first entry with the given `label`.
+    #[must_use]
+    pub fn get(&self, label: &str) -> Option<&KeystoreEntry> {
+        self.entries.iter().find(|it| it.label.as_deref() == Some(label))
+    }
+
+    /// Returns the first entry for the given `account_id`.
+    #[must_use]
+    pub fn get_by_account_id(&self, account_id: AccountId) -> Option<&KeystoreEntry> {
+        self.entries.iter().find(|it| it.account_id == Some(account_id))
+    }
+
+    /// Returns true if keys are saved with the cheaper scrypt parameters geth uses with `--lightkdf`.
+    #[must_use]
+    pub fn is_light_kdf(&self) -> bool {
+        self.light_kdf
+    }
+
+    /// Sets whether keys are saved with the cheaper scrypt parameters geth uses with `--lightkdf`
+    /// (`N = 2^12, p = 6`, rather than `N = 2^18, p = 1`).
+    ///
+    /// This is much faster (and uses much less memory), but it's also much faster to brute force.
+    pub fn set_light_kdf(&mut self, light_kdf: bool) -> &mut Self {
+        self.light_kdf = light_kdf;
+        self
+    }
+
+    /// Parse and decrypt a `Keystore` from JSON.
+    ///
+    /// # Errors
+    /// - [`Error::Keystore`] if `json` isn't a valid keystore, or if `password` is wrong.
+    /// - [`Error::KeyParse`] if a decrypted key isn't a valid `PrivateKey`.
+    pub fn from_json(json: &str, password: impl AsRef<[u8]>) -> crate::Result<Self> {
+        let password = password.as_ref();
+
+        let entries = match serde_json::from_str(json).map_err(Error::keystore)? {
+            KeystoreJson::Single(entry) => Vec::from([entry]),
+            KeystoreJson::Multiple(entries) => entries,
+        };
+
+        let entries = entries
+            .into_iter()
+            .map(|entry| entry.decrypt(password))
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { entries, light_kdf: false })
+    }
+
+    /// Encrypt this `Keystore` and serialize it as JSON.
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn to_json(&self, password: impl AsRef<[u8]>) -> String {
+        let password = password.as_ref();
+        let cost = if self.light_kdf { LIGHT_SCRYPT } else { STANDARD_SCRYPT };
+
+        let mut entries: Vec<_> =
+            self.entries.iter().map(|entry| EntryJson::encrypt(entry, password, cost)).collect();
+
+        let json = match entries.len() {
+            1 => serde_json::to_string_pretty(&entries.remove(0)),
+            _ => serde_json::to_string_pretty(&entries),
+        };
+
+        json.expect("BUG: keystore JSON should always serialize")
+    }
+
+    /// Read and decrypt a `Keystore` from the file at `path`.
+    ///
+    /// # Errors
+    /// - [`Error::Keystore`] if the file can't be read, isn't a valid keystore, or if `password` is wrong.
+    /// - [`Error::KeyParse`] if a decrypted key isn't a valid `PrivateKey`.
+    pub fn load(path: impl AsRef<Path>, password: impl AsRef<[u8]>) -> crate::Result<Self> {
+        let json = fs::read_to_string(path).map_err(Error::keystore)?;
+
+        Self::from_json(&json, password)
+    }
+
+    /// Encrypt this `Keystore` and write it to the file at `path`, replacing the file if it exists.
+    ///
+    /// On unix platforms, a new file is only readable and writable by its owner.
+    ///
+    /// # Errors
+    /// - [`Error::Keystore`] if the file can't be written.
+    pub fn save(&self, path: impl AsRef<Path>, password: impl AsRef<[u8]>) -> crate::Result<()> {
+        let json = self.to_json(password);
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(path).map_err(Error::keystore)?;
+
+        file.write_all(json.as_bytes()).map_err(Error::keystore)
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+#[serde(untagged)]
+enum KeystoreJson {
+    Single(EntryJson),
+    Multiple(Vec<EntryJson>),
+}
+
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EntryJson {
+    version: u32,
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    // geth used to write `Crypto`.
+    #[serde(alias = "Crypto")]
+    crypto: CryptoJson,
+    // plain Ethereum keystores only hold ECDSA keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key_type: Option<KeyTypeJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    account_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ledger_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum KeyTypeJson {
+    Ed25519,
+    Ecdsa,
+}
+
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+struct CryptoJson {
+    cipher: String,
+    cipherparams: CipherParamsJson,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParamsJson,
+    mac: String,
+}
+
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+struct CipherParamsJson {
+    iv: String,
+}
+
+/// The parameters of either scrypt (`n`, `r`, `p`) or PBKDF2 (`c`, `prf`).
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+struct KdfParamsJson {
+    dklen: usize,
+    salt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    n: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    r: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    p: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    c: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prf: Option<String>,
+}
+
+/// The MAC of `ciphertext`, authenticated with the second half of the derived key.
+fn mac(derived_key: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    Keccak256::new().chain_update(&derived_key[16..32]).chain_update(ciphertext).finalize().into()
+}
+
+fn decode_hex(field: &str, s: &str) -> crate::Result<Vec<u8>> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s))
+        .map_err(|e| Error::keystore(format!("invalid `{field}`: {e}")))
+}
+
+impl EntryJson {
+    fn encrypt(entry: &KeystoreEntry, password: &[u8], (log_n, p): (u8, u32)) -> Self {
+        let mut rng = rand::thread_rng();
+
+        let salt: [u8; 32] = rng.gen();
+        let iv: [u8; 16] = rng.gen();
+
+        let params = scrypt::Params::new(log_n, 8, p, 32).expect("scrypt params should be valid");
+        let mut derived_key = Zeroizing::new([0; 32]);
+        scrypt::scrypt(password, &salt, &params, &mut *derived_key)
+            .expect("32 should be a valid scrypt output length");
+
+        let mut ciphertext = entry.private_key.to_bytes_raw();
+        Aes128Ctr::new(derived_key[..16].into(), &iv.into()).apply_keystream(&mut ciphertext);
+
+        let private_key = &entry.private_key;
+
+        // random (v4) UUID.
+        let mut id: [u8; 16] = rng.gen();
+        id[6] = (id[6] & 0x0f) | 0x40;
+        id[8] = (id[8] & 0x3f) | 0x80;
+        let id = hex::encode(id);
+
+        Self {
+            version: 3,
+            id: format!(
+                "{}-{}-{}-{}-{}",
+                &id[..8],
+                &id[8..12],
+                &id[12..16],
+                &id[16..20],
+                &id[20..]
+            ),
+            address: private_key.public_key().to_evm_address().map(|it| hex::encode(it.to_bytes())),
+            crypto: CryptoJson {
+                cipher: "aes-128-ctr".to_owned(),
+                cipherparams: CipherParamsJson { iv: hex::encode(iv) },
+                ciphertext: hex::encode(&ciphertext),
+                kdf: "scrypt".to_owned(),
+                kdfparams: KdfParamsJson {
+                    dklen: 32,
+                    salt: hex::encode(salt),
+                    n: Some(1 << log_n),
+                    r: Some(8),
+                    p: Some(p),
+                    c: None,
+                    prf: None,
+                },
+                mac: hex::encode(mac(&*derived_key, &ciphertext)),
+            },
+            key_type: Some(match private_key.is_ed25519() {
+                true => KeyTypeJson::Ed25519,
+                false => KeyTypeJson::Ecdsa,
+            }),
+            account_id: entry.account_id.map(|it| it.to_string()),
+            ledger_id: entry.ledger_id.as_ref().map(ToString::to_string),
+            label: entry.label.clone(),
+        }
+    }
+
+    fn decrypt(self, password: &[u8]) -> crate::Result<KeystoreEntry> {
+        if self.version != 3 {
+            return Err(Error::keystore(format!(
+                "unsupported keystore version `{}`, expected `3`",
+                self.version
+            )));
+        }
+
+        let crypto = self.crypto;
+
+        if crypto.cipher != "aes-128-ctr" {
+            return Err(Error::keystore(format!(
+                "unsupported cipher `{}`, expected `aes-128-ctr`",
+                crypto.cipher
+            )));
+        }
+
+        let params = crypto.kdfparams;
+
+        // every supported cipher uses the first half of the derived key, and the MAC uses the second half.
+        if params.dklen != 32 {
+            return Err(Error::keystore(format!(
+                "unsupported `dklen` `{}`, expected `32`",
+                params.dklen
+            )));
+        }
+
+        let salt = decode_hex("salt", &params.salt)?;
+        let mut derived_key = Zeroizing::new([0; 32]);
+
+        // the parameters are limited so that a malicious keystore can't use unbounded time or memory.
+        match (crypto.kdf.as_str(), params) {
+            ("scrypt", KdfParamsJson { n: Some(n), r: Some(r), p: Some(p), .. }) => {
+                if !n.is_power_of_two() || n < 2 {
+                    return Err(Error::keystore("scrypt `n` must be a power of two"));
+                }
+
+                let log_n = n.trailing_zeros();
+
+                if log_n > MAX_SCRYPT_LOG_N {
+                    return Err(Error::keystore(format!(
+                        "scrypt `n` must be at most `2^{MAX_SCRYPT_LOG_N}`, found `2^{log_n}`"
+                    )));
+                }
+
+                if r > MAX_SCRYPT_R {
+                    return Err(Error::keystore(format!(
+                        "scrypt `r` must be at most `{MAX_SCRYPT_R}`, found `{r}`"
+                    )));
+                }
+
+                if p > MAX_SCRYPT_P {
+                    return Err(Error::keystore(format!(
+                        "scrypt `p` must be at most `{MAX_SCRYPT_P}`, found `{p}`"
+                    )));
+                }
+
+                let params = scrypt::Params::new(log_n as u8, r, p, 32).map_err(Error::keystore)?;
+
+                scrypt::scrypt(password, &salt, &params, &mut *derived_key)
+                    .map_err(Error::keystore)?;
+            }
+            ("pbkdf2", KdfParamsJson { c: Some(c), prf: Some(prf), .. })
+                if prf == "hmac-sha256" =>
+            {
+                if c > MAX_PBKDF2_C {
+                    return Err(Error::keystore(format!(
+                        "pbkdf2 `c` must be at most `{MAX_PBKDF2_C}`, found `{c}`"
+                    )));
+                }
+
+                pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password, &salt, c, &mut *derived_key);
+            }
+            (kdf, _) => {
+                return Err(Error::keystore(format!(
+                    "unsupported or misconfigured key derivation function `{kdf}`"
+                )))
+            }
+        }
+
+        let mut data = Zeroizing::new(decode_hex("ciphertext", &crypto.ciphertext)?);
+
+        if decode_hex("mac", &crypto.mac)? != mac(&*derived_key, &data) {
+            return Err(Error::keystore("MAC mismatch, the password is probably wrong"));
+        }
+
+        let iv: [u8; 16] = decode_hex("iv", &crypto.cipherparams.iv)?
+            .try_into()
+            .map_err(|_| Error::keystore("`iv` must be 16 bytes"))?;
+
+        Aes128Ctr::new(derived_key[..16].into(), &iv.into()).apply_keystream(&mut data);
+
+        let private_key = match self.key_type {
+            Some(KeyTypeJson::Ed25519) => PrivateKey::from_bytes_ed25519(&data)?,
+            Some(KeyTypeJson::Ecdsa) | None => PrivateKey::from_bytes_ecdsa(&data)?,
+        };
+
+        Ok(KeystoreEntry {
+            private_key,
+            account_id: self.account_id.as_deref().map(str::parse).transpose()?,
+            ledger_id: self.ledger_id.as_deref().map(str::parse).transpose()?,
+            label: self.label,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use super::{
+        Keystore,
+        KeystoreEntry,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        LedgerId,
+        PrivateKey,
+    };
+
+    #[test]
+    fn round_trip() {
+        let mut operator = KeystoreEntry::new(PrivateKey::generate_ed25519());
+        operator.account_id = Some(AccountId::new(0, 0, 1001));
+        operator.ledger_id = Some(LedgerId::testnet());
+        operator.label = Some("operator".to_owned());
+
+        let other = KeystoreEntry::new(PrivateKey::generate_ecdsa());
+
+        let mut keystore = Keystore::new();
+        keystore.set_light_kdf(true).add(operator.clone()).add(other.clone());
+
+        let json = keystore.to_json("hunter2");
+
+        let keystore = Keystore::from_json(&json, "hunter2").unwrap();
+
+        let entry = keystore.get("operator").unwrap();
+        assert_eq!(entry.private_key.to_bytes(), operator.private_key.to_bytes());
+        assert_eq!(entry.account_id, operator.account_id);
+        assert_eq!(entry.ledger_id, operator.ledger_id);
+
+        let entry = &keystore.entries()[1];
+        assert_eq!(entry.private_key.to_bytes(), other.private_key.to_bytes());
+        assert_eq!(entry.label, None);
+
+        assert_eq!(
+            keystore.get_by_account_id(AccountId::new(0, 0, 1001)).unwrap().label.as_deref(),
+            Some("operator")
+        );
+
+        assert_matches!(Keystore::from_json(&json, "hunter3"), Err(Error::Keystore(_)));
+    }
+
+    // the PBKDF2 test vector from the Web3 Secret Storage definition.
+    const ETHEREUM_PBKDF2: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": {
+                "iv": "6087dab2f9fdbbfaddc31a909735c1e6"
+            },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    /// Returns `json` (a single keystore entry) with its KDF parameter `name` replaced by `value`.
+    fn with_kdf_param(json: &str, name: &str, value: u64) -> String {
+        let mut json: serde_json::Value = serde_json::from_str(json).unwrap();
+        json["crypto"]["kdfparams"][name] = value.into();
+        json.to_string()
+    }
+
+    fn light_scrypt() -> String {
+        let mut keystore = Keystore::new();
+        keystore.set_light_kdf(true).add(KeystoreEntry::new(PrivateKey::generate_ed25519()));
+        keystore.to_json("hunter2")
+    }
+
+    fn assert_limit_error(json: &str, password: &str, message: &str) {
+        assert_matches!(
+            Keystore::from_json(json, password),
+            Err(Error::Keystore(e)) if e.to_string().contains(message),
+            "expected an error containing `{message}`"
+        );
+    }
+
+    #[test]
+    fn ethereum_pbkdf2() {
+        let keystore = Keystore::from_json(ETHEREUM_PBKDF2, "testpassword").unwrap();
+
+        let [entry] = keystore.entries() else { panic!("expected exactly one entry") };
+
+        assert!(entry.private_key.is_ecdsa());
+        assert_eq!(
+            *entry.private_key.to_bytes_raw(),
+            hex!("7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d")
+        );
+    }
+
+    #[test]
+    fn dklen_must_be_32() {
+        for dklen in [16, 64] {
+            let json = with_kdf_param(ETHEREUM_PBKDF2, "dklen", dklen);
+            assert_limit_error(&json, "testpassword", "dklen");
+        }
+    }
+
+    #[test]
+    fn scrypt_n_limit() {
+        let json = with_kdf_param(&light_scrypt(), "n", 1 << 21);
+        assert_limit_error(&json, "hunter2", "scrypt `n` must be at most");
+    }
+
+    #[test]
+    fn scrypt_r_limit() {
+        let json = with_kdf_param(&light_scrypt(), "r", 9);
+        assert_limit_error(&json, "hunter2", "scrypt `r` must be at most");
+    }
+
+    #[test]
+    fn scrypt_p_limit() {
+        let json = with_kdf_param(&light_scrypt(), "p", 17);
+        assert_limit_error(&json, "hunter2", "scrypt `p` must be at most");
+    }
+
+    #[test]
+    fn pbkdf2_c_limit() {
+        let json = with_kdf_param(ETHEREUM_PBKDF2, "c", (1 << 22) + 1);
+        assert_limit_error(&json, "testpassword", "pbkdf2 `c` must be at most");
+    }
+}
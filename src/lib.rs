@@ -119,6 +119,7 @@ mod protobuf;
 
 mod account;
 mod address_book;
+mod circuit_breaker;
 mod client;
 mod contract;
 mod downcast;
@@ -130,22 +131,30 @@ mod execute;
 mod fee_schedules;
 mod file;
 mod hbar;
+mod interceptor;
 mod key;
+#[cfg(feature = "serde")]
+mod keystore;
 mod ledger_id;
 mod mirror_query;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
+#[cfg(feature = "mock")]
+mod mock;
+mod network_stats;
 mod network_version_info;
 mod network_version_info_query;
 mod node_address;
 mod node_address_book;
 mod node_address_book_query;
+mod node_selector;
 mod pending_airdrop_id;
 mod pending_airdrop_record;
 mod ping_query;
 mod prng_transaction;
 mod query;
 mod retry;
+mod retry_policy;
 mod schedule;
 mod semantic_version;
 mod service_endpoint;
@@ -188,9 +197,23 @@ pub use address_book::{
     NodeDeleteTransaction,
     NodeUpdateTransaction,
 };
+pub use circuit_breaker::{
+    CircuitBreakerConfig,
+    CircuitState,
+};
 pub use client::Client;
 pub(crate) use client::Operator;
+#[cfg(feature = "serde")]
+pub use contract::{
+    AbiError,
+    AbiEvent,
+    AbiFunction,
+    AbiParam,
+    ContractAbi,
+};
 pub use contract::{
+    AbiType,
+    AbiValue,
     ContractBytecodeQuery,
     ContractCallQuery,
     ContractCreateFlow,
@@ -204,6 +227,7 @@ pub use contract::{
     ContractInfoQuery,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevert,
     ContractUpdateTransaction,
     DelegateContractId,
 };
@@ -218,8 +242,12 @@ pub use error::{
     MnemonicEntropyError,
     MnemonicParseError,
 };
+#[cfg(feature = "serde")]
+pub use ethereum::TypedData;
 pub use ethereum::{
+    AccessListItem,
     Eip1559EthereumData,
+    Eip2930EthereumData,
     EthereumData,
     EthereumFlow,
     EthereumTransaction,
@@ -256,11 +284,27 @@ pub use hbar::{
     Tinybar,
 };
 pub use hedera_proto::services::ResponseCodeEnum as Status;
+pub use interceptor::{
+    AttemptDecision,
+    AttemptInfo,
+    AttemptOutcome,
+    InterceptedRequest,
+    Interceptor,
+};
 pub use key::{
+    DerivationPath,
+    ExtendedPublicKey,
     Key,
     KeyList,
+    KeySatisfaction,
     PrivateKey,
     PublicKey,
+    RedactedPrivateKey,
+};
+#[cfg(feature = "serde")]
+pub use keystore::{
+    Keystore,
+    KeystoreEntry,
 };
 pub use ledger_id::LedgerId;
 pub use mirror_query::{
@@ -269,7 +313,25 @@ pub use mirror_query::{
     MirrorQuery,
 };
 #[cfg(feature = "mnemonic")]
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{
+    Mnemonic,
+    MnemonicLanguage,
+    RedactedMnemonic,
+};
+#[cfg(feature = "mock")]
+pub use mock::{
+    MockNetwork,
+    MockRequest,
+    MockResponse,
+    MockTopicItem,
+};
+pub use network_stats::{
+    LatencyHistogram,
+    NetworkStats,
+    NodeBackoffStats,
+    NodeStats,
+    RequestStats,
+};
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
@@ -277,6 +339,14 @@ pub use node_address::NodeAddress;
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
 pub(crate) use node_address_book_query::NodeAddressBookQueryData;
+pub use node_selector::{
+    LatencyWeightedNodeSelector,
+    NodeCandidate,
+    NodeSelector,
+    RandomNodeSelector,
+    RoundRobinNodeSelector,
+    StickyNodeSelector,
+};
 pub use pending_airdrop_record::PendingAirdropRecord;
 pub use prng_transaction::PrngTransaction;
 pub(crate) use protobuf::{
@@ -289,6 +359,13 @@ pub use query::{
     Query,
 };
 pub(crate) use retry::retry;
+pub use retry_policy::{
+    DefaultRetryPolicy,
+    FailedAttempt,
+    RetryDecision,
+    RetryPolicy,
+    StatusRetryPolicy,
+};
 pub use schedule::{
     ScheduleCreateTransaction,
     ScheduleDeleteTransaction,
@@ -299,6 +376,7 @@ pub use schedule::{
 };
 pub use semantic_version::SemanticVersion;
 pub use service_endpoint::ServiceEndpoint;
+pub use signer::Signer;
 pub use staking_info::StakingInfo;
 pub use system::{
     FreezeTransaction,
@@ -364,6 +442,8 @@ pub use topic::{
 };
 pub use transaction::{
     AnyTransaction,
+    OfflineFeePolicy,
+    OfflineTransactionBuilder,
     Transaction,
 };
 pub use transaction_hash::TransactionHash;
@@ -33,12 +33,18 @@ use rand::{
     RngCore,
 };
 use sha2::Digest;
+use unicode_normalization::UnicodeNormalization;
+use zeroize::{
+    Zeroize,
+    Zeroizing,
+};
 
 use crate::error::{
     MnemonicEntropyError,
     MnemonicParseError,
 };
 use crate::{
+    DerivationPath,
     Error,
     PrivateKey,
 };
@@ -65,6 +71,148 @@ static BIP39_WORD_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
 static LEGACY_WORD_LIST: Lazy<Vec<&'static str>> =
     Lazy::new(|| LEGACY.split_whitespace().collect());
 
+/// The language of the BIP-39 word list a [`Mnemonic`] uses.
+///
+/// Languages other than English require the `mnemonic-languages` feature.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MnemonicLanguage {
+    /// English, this is the default and the only language legacy mnemonics support.
+    #[default]
+    English,
+
+    /// Simplified Chinese.
+    #[cfg(feature = "mnemonic-languages")]
+    ChineseSimplified,
+
+    /// Traditional Chinese.
+    #[cfg(feature = "mnemonic-languages")]
+    ChineseTraditional,
+
+    /// French.
+    #[cfg(feature = "mnemonic-languages")]
+    French,
+
+    /// Italian.
+    #[cfg(feature = "mnemonic-languages")]
+    Italian,
+
+    /// Japanese.
+    #[cfg(feature = "mnemonic-languages")]
+    Japanese,
+
+    /// Korean.
+    #[cfg(feature = "mnemonic-languages")]
+    Korean,
+
+    /// Spanish.
+    #[cfg(feature = "mnemonic-languages")]
+    Spanish,
+}
+
+impl MnemonicLanguage {
+    #[cfg(not(feature = "mnemonic-languages"))]
+    const ALL: &'static [Self] = &[Self::English];
+
+    // English goes first, so that it wins when words are shared between languages.
+    #[cfg(feature = "mnemonic-languages")]
+    const ALL: &'static [Self] = &[
+        Self::English,
+        Self::ChineseSimplified,
+        Self::ChineseTraditional,
+        Self::French,
+        Self::Italian,
+        Self::Japanese,
+        Self::Korean,
+        Self::Spanish,
+    ];
+
+    fn word_list(self) -> &'static [&'static str] {
+        // the word lists are already NFKD normalized, which is how words are compared.
+        #[cfg(feature = "mnemonic-languages")]
+        macro_rules! word_list {
+            ($file:literal) => {{
+                static WORD_LIST: Lazy<Vec<&'static str>> =
+                    Lazy::new(|| include_str!($file).split_whitespace().collect());
+
+                WORD_LIST.as_slice()
+            }};
+        }
+
+        match self {
+            Self::English => BIP39_WORD_LIST.as_slice(),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::ChineseSimplified => word_list!("bip39-chinese-simplified.txt"),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::ChineseTraditional => word_list!("bip39-chinese-traditional.txt"),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::French => word_list!("bip39-french.txt"),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::Italian => word_list!("bip39-italian.txt"),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::Japanese => word_list!("bip39-japanese.txt"),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::Korean => word_list!("bip39-korean.txt"),
+            #[cfg(feature = "mnemonic-languages")]
+            Self::Spanish => word_list!("bip39-spanish.txt"),
+        }
+    }
+
+    fn index_of(self, word: &str) -> Option<u16> {
+        let index = match self {
+            // only the English list is guaranteed to be sorted (bytewise).
+            Self::English => BIP39_WORD_LIST.binary_search(&word).ok(),
+            #[cfg(feature = "mnemonic-languages")]
+            _ => self.word_list().iter().position(|it| *it == word),
+        };
+
+        index.map(|it| it as u16)
+    }
+
+    /// Japanese mnemonics are separated by ideographic spaces.
+    fn separator(self) -> char {
+        match self {
+            #[cfg(feature = "mnemonic-languages")]
+            Self::Japanese => '\u{3000}',
+            _ => ' ',
+        }
+    }
+
+    /// Returns the words in this language's word list that are closest to `word`, for suggesting fixes to typos.
+    ///
+    /// Only words that are at most a couple of edits away are returned,
+    /// and nothing is returned for words that are too short for a guess to be meaningful.
+    #[must_use]
+    pub fn closest_words(self, word: &str) -> Vec<&'static str> {
+        let word: String = word.nfkd().collect();
+
+        // allow one edit for every 3 characters, up to 2.
+        let max_distance = (word.chars().count() / 3).min(2);
+
+        let mut closest = Vec::new();
+        let mut closest_distance = max_distance;
+
+        for &candidate in self.word_list() {
+            let distance = edit_distance(&word, candidate);
+
+            if distance == 0 || distance > closest_distance {
+                continue;
+            }
+
+            if distance < closest_distance {
+                closest.clear();
+                closest_distance = distance;
+            }
+
+            closest.push(candidate);
+        }
+
+        closest.truncate(3);
+
+        closest
+    }
+}
+
 ///  `BIP-39` 24-word mnemonic phrase compatible with the Android and iOS mobile wallets.
 pub struct Mnemonic(MnemonicData);
 
@@ -78,94 +226,162 @@ impl fmt::Debug for Mnemonic {
     }
 }
 
+/// A [`Mnemonic`] that's formatted without its words, see [`Mnemonic::redacted`].
+#[derive(Clone, Copy)]
+pub struct RedactedMnemonic<'a>(&'a Mnemonic);
+
+impl fmt::Debug for RedactedMnemonic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("word_count", &self.0.words().len())
+            .field("is_legacy", &self.0.is_legacy())
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for RedactedMnemonic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<redacted {} word mnemonic>", self.0.words().len())
+    }
+}
+
 impl Mnemonic {
-    // todo(sr): before release, try to find a better internal representation
-    // lets not expose this until we know what the final signature should be
-    pub(crate) fn words(&self) -> &[String] {
+    /// Returns the words of this `Mnemonic`.
+    #[must_use]
+    pub fn words(&self) -> &[String] {
         match &self.0 {
             MnemonicData::V1(it) => it.words(),
             MnemonicData::V2V3(it) => it.words(),
         }
     }
 
+    /// Returns the language of this `Mnemonic`'s words.
+    #[must_use]
+    pub fn language(&self) -> MnemonicLanguage {
+        match &self.0 {
+            MnemonicData::V1(_) => MnemonicLanguage::English,
+            MnemonicData::V2V3(it) => it.language,
+        }
+    }
+
     /// Returns `true` if `self` is a legacy mnemonic.
     #[must_use]
     pub fn is_legacy(&self) -> bool {
         matches!(&self.0, MnemonicData::V1(_))
     }
 
+    /// Returns a wrapper that formats `self` without revealing its words.
+    ///
+    /// Useful for mnemonics that could otherwise end up in logs.
+    #[must_use]
+    pub fn redacted(&self) -> RedactedMnemonic<'_> {
+        RedactedMnemonic(self)
+    }
+
     // todo(sr): Not too happy about requiring a `Vec<String>`
     /// Constructs a `Mnemonic` from a 24-word list.
     ///
+    /// The language of the words is detected from the available [`MnemonicLanguage`]s.
+    ///
     /// # Errors
     /// * if the mnemonic has an invalid length.
-    /// * if the mnemonic uses invalid words.
+    /// * if the mnemonic uses invalid words, the error message suggests the closest valid words.
     /// * if the mnemonic has an invalid checksum.
+    // the only panic here should be unreachable, if it isn't, that's a library bug.
+    #[allow(clippy::missing_panics_doc)]
     pub fn from_words(words: Vec<String>) -> crate::Result<Self> {
         let words = match words.try_into() {
             Ok(words) => return Ok(Self(MnemonicData::V1(MnemonicV1 { words: Box::new(words) }))),
             Err(words) => words,
         };
 
-        let mnemonic = Self(MnemonicV2V3 { words }.into());
+        // the word lists are NFKD normalized.
+        let words: Vec<String> = words.into_iter().map(|it| it.nfkd().collect()).collect();
+
+        let mnemonic = |words, language| Self(MnemonicV2V3 { words, language }.into());
 
-        if mnemonic.words().len() != 12 && mnemonic.words().len() != 24 {
+        if words.len() != 12 && words.len() != 24 {
             return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::BadLength(mnemonic.words().len()),
-                mnemonic,
+                reason: MnemonicParseError::BadLength(words.len()),
+                mnemonic: mnemonic(words, MnemonicLanguage::English),
             });
         }
 
-        let mut word_indecies = Vec::with_capacity(mnemonic.words().len());
-        let mut unknown_words = Vec::new();
-        for (word_index, word) in mnemonic.words().iter().enumerate() {
-            match BIP39_WORD_LIST.binary_search(&&**word) {
-                Ok(i) => {
-                    word_indecies.push(i as u16);
+        // the language with the fewest unknown words is the one errors get reported for.
+        let mut unknown_words: Option<(MnemonicLanguage, Vec<usize>)> = None;
+        let mut checksum_mismatch = None;
+
+        for &language in MnemonicLanguage::ALL {
+            let mut word_indecies = Vec::with_capacity(words.len());
+            let mut unknown = Vec::new();
+            for (word_index, word) in words.iter().enumerate() {
+                match language.index_of(word) {
+                    Some(i) => word_indecies.push(i),
+                    // error (word not in list)
+                    None => unknown.push(word_index),
                 }
-                // error (word not in list)
-                Err(_) => {
-                    unknown_words.push(word_index);
+            }
+
+            if !unknown.is_empty() {
+                if unknown_words.as_ref().map_or(true, |(_, it)| unknown.len() < it.len()) {
+                    unknown_words = Some((language, unknown));
                 }
+
+                continue;
             }
-        }
 
-        if !unknown_words.is_empty() {
-            return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::UnknownWords(unknown_words),
-                mnemonic,
-            });
-        }
+            let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(&word_indecies);
+            let expected_checksum = checksum(&entropy);
+            let expected_checksum =
+                if words.len() == 12 { expected_checksum & 0xf0 } else { expected_checksum };
 
-        let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(&word_indecies);
-        let expected_checksum = checksum(&entropy);
-        let expected_checksum =
-            if mnemonic.words().len() == 12 { expected_checksum & 0xf0 } else { expected_checksum };
+            if expected_checksum != actual_checksum {
+                checksum_mismatch.get_or_insert((language, expected_checksum, actual_checksum));
+                continue;
+            }
 
-        if expected_checksum != actual_checksum {
+            return Ok(mnemonic(words, language));
+        }
+
+        if let Some((language, expected, actual)) = checksum_mismatch {
             // error: checksum mismatch.
             return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::ChecksumMismatch {
-                    expected: expected_checksum,
-                    actual: actual_checksum,
-                },
-                mnemonic,
+                reason: MnemonicParseError::ChecksumMismatch { expected, actual },
+                mnemonic: mnemonic(words, language),
             });
         }
 
-        Ok(mnemonic)
+        let (language, unknown_words) =
+            unknown_words.expect("BUG: every language either matched or had unknown words");
+
+        Err(Error::MnemonicParse {
+            reason: MnemonicParseError::UnknownWords(unknown_words),
+            mnemonic: mnemonic(words, language),
+        })
     }
 
     /// Generate a new 12 word `Mnemonic` from the BIP-39 standard English word list.
     #[must_use]
     pub fn generate_12() -> Self {
-        Self(MnemonicV2V3::generate_12().into())
+        Self::generate_12_with_language(MnemonicLanguage::English)
     }
 
     /// Generate a new 24 word `Mnemonic` from the BIP-39 standard English word list.
     #[must_use]
     pub fn generate_24() -> Self {
-        Self(MnemonicV2V3::generate_24().into())
+        Self::generate_24_with_language(MnemonicLanguage::English)
+    }
+
+    /// Generate a new 12 word `Mnemonic` from the BIP-39 standard word list for `language`.
+    #[must_use]
+    pub fn generate_12_with_language(language: MnemonicLanguage) -> Self {
+        Self(MnemonicV2V3::generate_12(language).into())
+    }
+
+    /// Generate a new 24 word `Mnemonic` from the BIP-39 standard word list for `language`.
+    #[must_use]
+    pub fn generate_24_with_language(language: MnemonicLanguage) -> Self {
+        Self(MnemonicV2V3::generate_24(language).into())
     }
 
     /// Recover a [`PrivateKey`] from this `Mnemonic`.
@@ -205,25 +421,77 @@ impl Mnemonic {
         }
     }
 
-    pub(crate) fn to_seed(&self, phrase: &str) -> [u8; 64] {
-        let mut salt = String::from("mnemonic");
-        salt.push_str(phrase);
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic` the standard (BIP-32/BIP-44) way,
+    /// by deriving the key at `path` from the BIP-39 seed of this `Mnemonic` and `passphrase`.
+    ///
+    /// The other Hedera SDKs derive the key at `index` from `m/44'/3030'/0'/0/<index>`,
+    /// Ethereum wallets such as MetaMask use `m/44'/60'/0'/0/<index>`.
+    ///
+    /// # Errors
+    /// - [`Error::MnemonicEntropy`] with [`MnemonicEntropyError::LegacyWithPassphrase`]
+    ///   if this is a legacy mnemonic, and the passphrase isn't empty.
+    /// - [`Error::KeyDerive`] if a derived key is invalid (astronomically unlikely).
+    pub fn to_standard_ecdsa_private_key(
+        &self,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> crate::Result<PrivateKey> {
+        if self.is_legacy() && !passphrase.is_empty() {
+            return Err(Error::from(MnemonicEntropyError::LegacyWithPassphrase));
+        }
+
+        PrivateKey::from_seed_ecdsa(&*self.to_seed(passphrase))?.derive_path(path)
+    }
+
+    /// Returns the BIP-39 seed, both the mnemonic and the passphrase are NFKD normalized as BIP-39 requires.
+    pub(crate) fn to_seed(&self, phrase: &str) -> Zeroizing<[u8; 64]> {
+        let mut salt = Zeroizing::new(String::from("mnemonic"));
+        salt.extend(phrase.nfkd());
+
+        let mnemonic: Zeroizing<String> =
+            Zeroizing::new(Zeroizing::new(self.to_string()).nfkd().collect());
 
-        pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(
-            self.to_string().as_bytes(),
+        Zeroizing::new(pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(
+            mnemonic.as_bytes(),
             salt.as_bytes(),
             2048,
-        )
+        ))
+    }
+
+    /// Suggestions for the unknown words of a mnemonic that failed to parse, for the error message.
+    pub(crate) fn typo_hint(&self, reason: &MnemonicParseError) -> String {
+        let MnemonicParseError::UnknownWords(indecies) = reason else {
+            return String::new();
+        };
+
+        let language = self.language();
+
+        let hints: Vec<_> = indecies
+            .iter()
+            .filter_map(|&index| {
+                let word = self.words().get(index)?;
+                let closest = language.closest_words(word);
+
+                (!closest.is_empty()).then(|| format!("`{word}` -> `{}`", closest.join("` or `")))
+            })
+            .collect();
+
+        match hints.is_empty() {
+            true => String::new(),
+            false => format!(" (did you mean: {})", hints.join(", ")),
+        }
     }
 }
 
 impl fmt::Display for Mnemonic {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = self.language().separator();
+
         if let Some((first, rest)) = self.words().split_first() {
             f.write_str(first)?;
 
             for word in rest {
-                write!(f, " {word}")?;
+                write!(f, "{separator}{word}")?;
             }
         }
 
@@ -243,6 +511,12 @@ struct MnemonicV1 {
     words: Box<[String; 22]>,
 }
 
+impl Drop for MnemonicV1 {
+    fn drop(&mut self) {
+        self.words.zeroize();
+    }
+}
+
 impl MnemonicV1 {
     // clippy bug.
     #[allow(clippy::explicit_auto_deref)]
@@ -283,6 +557,13 @@ impl MnemonicV1 {
 
 struct MnemonicV2V3 {
     words: Vec<String>,
+    language: MnemonicLanguage,
+}
+
+impl Drop for MnemonicV2V3 {
+    fn drop(&mut self) {
+        self.words.zeroize();
+    }
 }
 
 impl MnemonicV2V3 {
@@ -290,7 +571,7 @@ impl MnemonicV2V3 {
         &self.words
     }
 
-    fn from_entropy(entropy: &[u8]) -> Self {
+    fn from_entropy(entropy: &[u8], language: MnemonicLanguage) -> Self {
         assert!(entropy.len() == 16 || entropy.len() == 32);
 
         let entropy = {
@@ -311,28 +592,28 @@ impl MnemonicV2V3 {
             offset += 8;
             if offset >= 11 {
                 let index = (buffer >> (offset - 11) & 0x7ff) as usize;
-                words.push(BIP39_WORD_LIST[index].to_owned());
+                words.push(language.word_list()[index].to_owned());
                 offset -= 11;
             }
         }
 
-        Self { words }
+        Self { words, language }
     }
 
-    fn generate_12() -> Self {
+    fn generate_12(language: MnemonicLanguage) -> Self {
         let mut rng = thread_rng();
         let mut entropy = [0; 16];
         rng.fill_bytes(&mut entropy);
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(&entropy, language)
     }
 
-    fn generate_24() -> Self {
+    fn generate_24(language: MnemonicLanguage) -> Self {
         let mut rng = thread_rng();
         let mut entropy = [0; 32];
         rng.fill_bytes(&mut entropy);
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(&entropy, language)
     }
 
     fn to_legacy_entropy(&self) -> crate::Result<Vec<u8>> {
@@ -345,7 +626,7 @@ impl MnemonicV2V3 {
         }
 
         // technically, this code all works for 12 words, but I'm going to pretend it doesn't.
-        let (entropy, actual_checksum) = words_to_entropy_and_checksum(&self.words);
+        let (entropy, actual_checksum) = words_to_entropy_and_checksum(&self.words, self.language);
 
         let expected_checksum = checksum(&entropy);
         let expected_checksum =
@@ -422,12 +703,12 @@ fn convert_radix<I: IntoIterator<Item = i32>>(
     out
 }
 
-fn words_to_entropy_and_checksum<T: AsRef<str>>(words: &[T]) -> (Vec<u8>, u8) {
-    let indecies: Vec<_> = words
-        .iter()
-        .map(T::as_ref)
-        .map(|it| BIP39_WORD_LIST.binary_search(&it).unwrap() as u16)
-        .collect();
+fn words_to_entropy_and_checksum<T: AsRef<str>>(
+    words: &[T],
+    language: MnemonicLanguage,
+) -> (Vec<u8>, u8) {
+    let indecies: Vec<_> =
+        words.iter().map(T::as_ref).map(|it| language.index_of(it).unwrap()).collect();
 
     incecies_to_entropy_and_checksum(&indecies)
 }
@@ -467,6 +748,28 @@ fn checksum(bytes: &[u8]) -> u8 {
     checksum[0]
 }
 
+/// Levenshtein distance between `lhs` and `rhs`, in `char`s.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+
+    // `row[j]` is the distance between the prefix of `lhs` seen so far and `rhs[..j]`.
+    let mut row: Vec<usize> = (0..=rhs.len()).collect();
+
+    for (i, lhs_char) in lhs.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, &rhs_char) in rhs.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] =
+                (diagonal + usize::from(lhs_char != rhs_char)).min(row[j] + 1).min(above + 1);
+            diagonal = above;
+        }
+    }
+
+    row[rhs.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -475,7 +778,10 @@ mod tests {
     use expect_test::expect;
     use hex_literal::hex;
 
-    use super::Mnemonic;
+    use super::{
+        Mnemonic,
+        MnemonicLanguage,
+    };
     use crate::error::MnemonicParseError;
     use crate::Error;
 
@@ -493,6 +799,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn redacted() {
+        let mnemonic = Mnemonic::from_str(KNOWN_GOOD_MNEMONICS[0]).unwrap();
+
+        assert_eq!(mnemonic.redacted().to_string(), "<redacted 24 word mnemonic>");
+        assert!(!format!("{:?}", mnemonic.redacted()).contains("inmate"));
+        assert!(format!("{mnemonic:?}").contains("inmate"));
+    }
+
     #[test]
     fn error_invalid_length() {
         // we can't test for up to `usize` length, but we can test several lengths to be modestly sure.
@@ -563,6 +878,25 @@ mod tests {
         assert_eq!(reported_words, vec![6, 12, 17]);
     }
 
+    #[test]
+    fn unknown_words_suggestions() {
+        const MNEMONIC: &str = concat!(
+            "abandon ability able about above absent ",
+            "adsorb abstract absurd abuse access accident ",
+            "acount accuse achieve acid acoustic acquire ",
+            "across act action actor actress actual"
+        );
+
+        let error = Mnemonic::from_str(MNEMONIC).unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.contains("`adsorb` -> `absorb`"), "{message}");
+        assert!(message.contains("`acount` -> `account`"), "{message}");
+
+        assert_eq!(MnemonicLanguage::English.closest_words("abandom"), ["abandon"]);
+        assert!(MnemonicLanguage::English.closest_words("xyzzyxyzzy").is_empty());
+    }
+
     #[test]
     fn checksum_mismatch_1() {
         const MNEMONIC: &str = concat!(
@@ -602,7 +936,9 @@ mod tests {
         ];
 
         for (entropy, s) in ENTROPY.iter().zip(KNOWN_GOOD_MNEMONICS) {
-            let mnemonic = Mnemonic(super::MnemonicV2V3::from_entropy(entropy).into());
+            let mnemonic = Mnemonic(
+                super::MnemonicV2V3::from_entropy(entropy, super::MnemonicLanguage::English).into(),
+            );
 
             assert_eq!(&mnemonic.to_string(), s);
         }
@@ -680,4 +1016,37 @@ mod tests {
         "#]]
         .assert_debug_eq(key.debug_pretty());
     }
+
+    #[test]
+    fn to_standard_ecdsa_private_key() {
+        const MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        assert_eq!(mnemonic.words().len(), 12);
+        assert_eq!(mnemonic.language(), MnemonicLanguage::English);
+
+        // the first account of the default hardhat/anvil mnemonic, which MetaMask derives the same way.
+        let key = mnemonic
+            .to_standard_ecdsa_private_key("", &"m/44'/60'/0'/0/0".parse().unwrap())
+            .unwrap();
+
+        assert_eq!(
+            *key.to_bytes_raw(),
+            hex!("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+        );
+    }
+
+    #[cfg(feature = "mnemonic-languages")]
+    #[test]
+    fn languages_round_trip() {
+        for language in [MnemonicLanguage::Japanese, MnemonicLanguage::Spanish] {
+            let mnemonic = Mnemonic::generate_24_with_language(language);
+
+            let parsed = Mnemonic::from_str(&mnemonic.to_string()).unwrap();
+
+            assert_eq!(parsed.language(), language);
+            assert_eq!(parsed.words(), mnemonic.words());
+        }
+    }
 }
@@ -0,0 +1,414 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! An in-process mock of a Hedera network for testing without one.
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::io;
+use std::net::{
+    Ipv4Addr,
+    SocketAddr,
+};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use triomphe::Arc;
+
+use crate::{
+    AccountId,
+    Status,
+    TransactionId,
+    TransactionReceipt,
+    TransactionRecord,
+};
+
+mod service;
+
+/// A scripted response from a [`MockNetwork`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum MockResponse {
+    /// Respond with the given pre-check status.
+    ///
+    /// [`Status::Ok`] responds the same way as if nothing was scripted.
+    PreCheck(Status),
+
+    /// Fail the gRPC request itself with the given code.
+    Grpc(tonic::Code),
+
+    /// Respond to a receipt query with the given receipt.
+    Receipt(TransactionReceipt),
+
+    /// Respond to a record query with the given record.
+    Record(TransactionRecord),
+
+    /// Wait before responding, for instance to exceed the client's gRPC timeout.
+    Delayed(Duration, Box<MockResponse>),
+}
+
+/// A scripted item in a topic message subscription to a [`MockNetwork`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum MockTopicItem {
+    /// Send a message with the given contents.
+    ///
+    /// Sequence numbers and consensus timestamps are filled in by the mock.
+    Message(Vec<u8>),
+
+    /// End the subscription with the given gRPC code.
+    Error(tonic::Code),
+}
+
+/// A request received by a [`MockNetwork`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MockRequest {
+    /// The account ID of the node that received the request.
+    pub node_account_id: AccountId,
+
+    /// The gRPC method, such as `/proto.CryptoService/cryptoTransfer`.
+    pub method: String,
+
+    /// The transaction ID of the transaction, if the request was a transaction.
+    pub transaction_id: Option<TransactionId>,
+}
+
+#[derive(Default)]
+struct Shared {
+    responses: Mutex<VecDeque<MockResponse>>,
+    node_responses: Mutex<HashMap<AccountId, VecDeque<MockResponse>>>,
+    topic_subscriptions: Mutex<VecDeque<Vec<MockTopicItem>>>,
+    requests: Mutex<Vec<MockRequest>>,
+    nodes: Mutex<Vec<(AccountId, SocketAddr)>>,
+}
+
+impl Shared {
+    /// Returns the next response scripted for `node_account_id`, or for any node.
+    fn next_response(&self, node_account_id: AccountId) -> Option<MockResponse> {
+        let response =
+            self.node_responses.lock().get_mut(&node_account_id).and_then(VecDeque::pop_front);
+
+        response.or_else(|| self.responses.lock().pop_front())
+    }
+}
+
+/// Consensus nodes and a mirror node that respond with scripted responses, running in-process.
+///
+/// Use [`Client::for_mock`](crate::Client::for_mock) to get a client that talks to the mock.
+///
+/// Each request takes the next response scripted for the node it's sent to,
+/// then the next response scripted for any node, and if there isn't one it succeeds:
+/// transactions get an `OK` pre-check status and receipt and record queries get a `SUCCESS` receipt.
+///
+/// The pings and cost queries the client makes aren't scripted, they always succeed, and queries are free.
+/// Signatures aren't checked.
+///
+/// The servers stop when the `MockNetwork` is dropped.
+///
+/// # Examples
+/// ```no_run
+/// # async fn f() -> hedera::Result<()> {
+/// use hedera::{AccountId, Client, Hbar, MockNetwork, MockResponse, PrivateKey, Status, TransferTransaction};
+///
+/// let mock = MockNetwork::start(3).await.unwrap();
+/// let client = Client::for_mock(&mock);
+/// client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+///
+/// // the first node the transaction is sent to is busy.
+/// mock.push_response(MockResponse::PreCheck(Status::Busy));
+///
+/// let receipt = TransferTransaction::new()
+///     .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+///     .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+///     .execute(&client)
+///     .await?
+///     .get_receipt(&client)
+///     .await?;
+///
+/// assert_eq!(mock.requests().len(), 3);
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockNetwork {
+    shared: Arc<Shared>,
+    mirror_address: SocketAddr,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl MockNetwork {
+    /// Starts `nodes` consensus nodes (`0.0.3`, `0.0.4`, ...) and a mirror node, listening on localhost.
+    ///
+    /// # Errors
+    /// - If a server can't be bound to a local port.
+    pub async fn start(nodes: usize) -> io::Result<Self> {
+        let shared = Arc::new(Shared::default());
+        let mut tasks = Vec::with_capacity(nodes + 1);
+
+        for num in 0..nodes {
+            let node_account_id = AccountId::from(num as u64 + 3);
+            let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+
+            shared.nodes.lock().push((node_account_id, listener.local_addr()?));
+
+            tasks.push(tokio::spawn(service::serve_consensus_node(
+                listener,
+                node_account_id,
+                Arc::clone(&shared),
+            )));
+        }
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let mirror_address = listener.local_addr()?;
+
+        tasks.push(tokio::spawn(service::serve_mirror_node(listener, Arc::clone(&shared))));
+
+        Ok(Self { shared, mirror_address, tasks })
+    }
+
+    /// Returns the consensus nodes, in the form [`Client::for_network`](crate::Client::for_network) takes.
+    #[must_use]
+    pub fn network(&self) -> HashMap<String, AccountId> {
+        self.shared.nodes.lock().iter().map(|(id, address)| (address.to_string(), *id)).collect()
+    }
+
+    /// Returns the mirror node, in the form [`Client::set_mirror_network`](crate::Client::set_mirror_network) takes.
+    #[must_use]
+    pub fn mirror_network(&self) -> Vec<String> {
+        Vec::from([self.mirror_address.to_string()])
+    }
+
+    /// Adds a response for the next request that nothing more specific is scripted for, on any node.
+    pub fn push_response(&self, response: MockResponse) {
+        self.shared.responses.lock().push_back(response);
+    }
+
+    /// Adds a response for the next request to the node with the given account ID.
+    ///
+    /// These take priority over responses added with [`push_response`](Self::push_response).
+    pub fn push_node_response(&self, node_account_id: AccountId, response: MockResponse) {
+        self.shared.node_responses.lock().entry(node_account_id).or_default().push_back(response);
+    }
+
+    /// Adds the items that the next topic message subscription receives, after which it ends.
+    ///
+    /// Subscriptions that nothing is scripted for end immediately.
+    pub fn push_topic_subscription(&self, items: impl IntoIterator<Item = MockTopicItem>) {
+        self.shared.topic_subscriptions.lock().push_back(items.into_iter().collect());
+    }
+
+    /// Returns every request the consensus nodes have received so far, in the order they were received.
+    ///
+    /// The pings and cost queries the client makes aren't included.
+    #[must_use]
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.shared.requests.lock().clone()
+    }
+}
+
+impl Drop for MockNetwork {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+
+    use super::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        AccountId,
+        Client,
+        ContractId,
+        ContractRevert,
+        Hbar,
+        PrivateKey,
+        Status,
+        TransactionId,
+        TransactionReceipt,
+        TransactionRecord,
+        TransferTransaction,
+    };
+
+    async fn setup(nodes: usize) -> (MockNetwork, Client) {
+        let mock = MockNetwork::start(nodes).await.unwrap();
+        let client = Client::for_mock(&mock);
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+        (mock, client)
+    }
+
+    fn transfer() -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1));
+
+        tx
+    }
+
+    #[tokio::test]
+    async fn busy_node_fails_over() {
+        let (mock, client) = setup(2).await;
+
+        mock.push_response(MockResponse::PreCheck(Status::Busy));
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_ne!(requests[0].node_account_id, requests[1].node_account_id);
+        assert_eq!(requests[1].node_account_id, response.node_account_id);
+        assert_eq!(requests[1].transaction_id, Some(response.transaction_id));
+    }
+
+    #[tokio::test]
+    async fn receipt_polling() {
+        let (mock, client) = setup(1).await;
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        // not found, then not reached consensus, and then it succeeded.
+        mock.push_response(MockResponse::PreCheck(Status::ReceiptNotFound));
+        mock.push_response(MockResponse::Receipt(
+            TransactionReceipt::from_protobuf(services::TransactionReceipt {
+                status: Status::Unknown as i32,
+                ..Default::default()
+            })
+            .unwrap(),
+        ));
+
+        let receipt = response.get_receipt(&client).await.unwrap();
+
+        assert_eq!(receipt.status, Status::Success);
+        assert_eq!(mock.requests().len(), 4);
+    }
+
+    /// A record of a contract call that reverted with `InsufficientBalance(10, 3)`,
+    /// a custom error which needs the contract's ABI to be decoded.
+    fn reverted_record(transaction_id: TransactionId) -> TransactionRecord {
+        let revert_data = "0xcf47918100000000000000000000000000000000000000000000000000000000\
+            0000000a00000000000000000000000000000000000000000000000000000000\
+            00000003";
+
+        TransactionRecord::from_protobuf(services::TransactionRecord {
+            receipt: Some(services::TransactionReceipt {
+                status: Status::ContractRevertExecuted as i32,
+                ..Default::default()
+            }),
+            transaction_id: Some(transaction_id.to_protobuf()),
+            consensus_timestamp: Some(services::Timestamp::default()),
+            body: Some(services::transaction_record::Body::ContractCallResult(
+                services::ContractFunctionResult {
+                    contract_id: Some(ContractId::from(5).to_protobuf()),
+                    error_message: revert_data.to_owned(),
+                    ..Default::default()
+                },
+            )),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn contract_revert_in_record() {
+        let (mock, client) = setup(1).await;
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        mock.push_response(MockResponse::Record(reverted_record(response.transaction_id)));
+
+        let res = response.get_record(&client).await;
+
+        let Err(crate::Error::ContractRevert {
+            status: Status::ContractRevertExecuted,
+            revert,
+            ..
+        }) = res
+        else {
+            panic!("expected a contract revert, got {res:?}");
+        };
+
+        assert!(matches!(*revert, ContractRevert::Unknown(data) if data.len() == 68));
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn contract_revert_in_record_with_abi() {
+        let (mock, client) = setup(1).await;
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        mock.push_response(MockResponse::Record(reverted_record(response.transaction_id)));
+
+        let abi = crate::ContractAbi::from_json(
+            r#"[{
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [
+                    { "name": "required", "type": "uint256" },
+                    { "name": "available", "type": "uint256" }
+                ]
+            }]"#,
+        )
+        .unwrap();
+
+        let res = response.get_record_query().contract_abi(abi).execute(&client).await;
+
+        let Err(error) = res else {
+            panic!("expected a contract revert, got {res:?}");
+        };
+
+        assert!(matches!(
+            error,
+            crate::Error::ContractRevert { status: Status::ContractRevertExecuted, .. }
+        ));
+        assert!(
+            error.to_string().ends_with(": InsufficientBalance(required=10, available=3)"),
+            "{error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn grpc_error_fails() {
+        let (mock, client) = setup(1).await;
+
+        mock.push_response(MockResponse::Grpc(tonic::Code::PermissionDenied));
+
+        let res = transfer().execute(&client).await;
+
+        assert!(
+            matches!(res, Err(crate::Error::GrpcStatus(it)) if it.code() == tonic::Code::PermissionDenied)
+        );
+    }
+}
@@ -0,0 +1,531 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! The gRPC plumbing behind [`MockNetwork`](super::MockNetwork).
+//!
+//! The services are implemented by hand instead of with the generated server traits,
+//! so that the mock doesn't need every method of every service to be spelled out.
+
+use std::convert::Infallible;
+
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use hedera_proto::{
+    mirror,
+    services,
+};
+use prost::Message;
+use time::OffsetDateTime;
+use tokio::net::TcpListener;
+use tonic::codec::ProstCodec;
+use tonic::codegen::{
+    http,
+    Body,
+    Context,
+    Poll,
+    Service,
+    StdError,
+};
+use tonic::server::{
+    Grpc,
+    NamedService,
+    ServerStreamingService,
+    UnaryService,
+};
+use tonic::transport::Server;
+use triomphe::Arc;
+
+use super::{
+    MockRequest,
+    MockResponse,
+    MockTopicItem,
+    Shared,
+};
+use crate::protobuf::FromProtobuf;
+use crate::{
+    AccountId,
+    Status,
+    ToProtobuf,
+    TransactionId,
+};
+
+type HttpResponse = http::Response<tonic::body::BoxBody>;
+
+/// Handles every method of one or more gRPC services.
+trait Handler: Clone + Send + 'static {
+    fn handle<B>(self, request: http::Request<B>) -> BoxFuture<'static, HttpResponse>
+    where
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static;
+}
+
+macro_rules! named_services {
+    ($handler:ty { $($service:ident => $name:literal),* $(,)? }) => {
+        $(
+            #[derive(Clone)]
+            struct $service($handler);
+
+            impl NamedService for $service {
+                const NAME: &'static str = $name;
+            }
+
+            impl<B> Service<http::Request<B>> for $service
+            where
+                B: Body + Send + 'static,
+                B::Error: Into<StdError> + Send + 'static,
+            {
+                type Response = HttpResponse;
+                type Error = Infallible;
+                type Future = BoxFuture<'static, Result<HttpResponse, Infallible>>;
+
+                fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+                    Poll::Ready(Ok(()))
+                }
+
+                fn call(&mut self, request: http::Request<B>) -> Self::Future {
+                    let response = self.0.clone().handle(request);
+                    Box::pin(async move { Ok(response.await) })
+                }
+            }
+        )*
+    };
+}
+
+named_services!(ConsensusNode {
+    CryptoService => "proto.CryptoService",
+    FileService => "proto.FileService",
+    SmartContractService => "proto.SmartContractService",
+    ConsensusService => "proto.ConsensusService",
+    TokenService => "proto.TokenService",
+    ScheduleService => "proto.ScheduleService",
+    FreezeService => "proto.FreezeService",
+    NetworkService => "proto.NetworkService",
+    UtilService => "proto.UtilService",
+    AddressBookService => "proto.AddressBookService",
+});
+
+named_services!(MirrorNode {
+    MirrorConsensusService => "com.hedera.mirror.api.proto.ConsensusService",
+    MirrorNetworkService => "com.hedera.mirror.api.proto.NetworkService",
+});
+
+fn incoming(listener: TcpListener) -> BoxStream<'static, std::io::Result<tokio::net::TcpStream>> {
+    Box::pin(async_stream::stream! {
+        loop {
+            yield listener.accept().await.map(|(stream, _)| stream);
+        }
+    })
+}
+
+pub(super) async fn serve_consensus_node(
+    listener: TcpListener,
+    node_account_id: AccountId,
+    shared: Arc<Shared>,
+) {
+    let node = ConsensusNode { node_account_id, shared };
+
+    let res = Server::builder()
+        .add_service(CryptoService(node.clone()))
+        .add_service(FileService(node.clone()))
+        .add_service(SmartContractService(node.clone()))
+        .add_service(ConsensusService(node.clone()))
+        .add_service(TokenService(node.clone()))
+        .add_service(ScheduleService(node.clone()))
+        .add_service(FreezeService(node.clone()))
+        .add_service(NetworkService(node.clone()))
+        .add_service(UtilService(node.clone()))
+        .add_service(AddressBookService(node))
+        .serve_with_incoming(incoming(listener))
+        .await;
+
+    if let Err(e) = res {
+        log::error!("mock node {node_account_id} stopped: {e}");
+    }
+}
+
+pub(super) async fn serve_mirror_node(listener: TcpListener, shared: Arc<Shared>) {
+    let node = MirrorNode { shared };
+
+    let res = Server::builder()
+        .add_service(MirrorConsensusService(node.clone()))
+        .add_service(MirrorNetworkService(node))
+        .serve_with_incoming(incoming(listener))
+        .await;
+
+    if let Err(e) = res {
+        log::error!("mock mirror node stopped: {e}");
+    }
+}
+
+fn unimplemented(method: &str) -> tonic::Status {
+    tonic::Status::unimplemented(format!("`{method}` isn't supported by the mock network"))
+}
+
+fn mismatched(method: &str) -> tonic::Status {
+    tonic::Status::failed_precondition(format!("the scripted response doesn't fit `{method}`"))
+}
+
+/// Waits out any delay in `response`, and turns scripted gRPC errors into errors.
+async fn resolve(mut response: MockResponse) -> Result<MockResponse, tonic::Status> {
+    loop {
+        match response {
+            MockResponse::Delayed(delay, inner) => {
+                tokio::time::sleep(delay).await;
+                response = *inner;
+            }
+            MockResponse::Grpc(code) => {
+                return Err(tonic::Status::new(code, "scripted by the mock network"));
+            }
+            response => return Ok(response),
+        }
+    }
+}
+
+fn transaction_id(transaction: &services::Transaction) -> Option<TransactionId> {
+    let signed =
+        services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).ok()?;
+    let body = services::TransactionBody::decode(&*signed.body_bytes).ok()?;
+
+    TransactionId::from_protobuf(body.transaction_id?).ok()
+}
+
+fn success_receipt() -> services::TransactionReceipt {
+    services::TransactionReceipt { status: Status::Success as i32, ..Default::default() }
+}
+
+macro_rules! query_responses {
+    ($($query:ident => $response:ident),* $(,)?) => {
+        /// Returns the header of `query` and an empty response to it, if the query is supported.
+        fn query_response(
+            query: &services::query::Query,
+        ) -> Option<(Option<&services::QueryHeader>, services::response::Response)> {
+            match query {
+                $(
+                    services::query::Query::$query(it) => Some((
+                        it.header.as_ref(),
+                        services::response::Response::$response(Default::default()),
+                    )),
+                )*
+                _ => None,
+            }
+        }
+
+        fn header_mut(
+            response: &mut services::response::Response,
+        ) -> Option<&mut Option<services::ResponseHeader>> {
+            match response {
+                $(services::response::Response::$response(it) => Some(&mut it.header),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+query_responses! {
+    CryptogetAccountBalance => CryptogetAccountBalance,
+    CryptoGetInfo => CryptoGetInfo,
+    CryptoGetAccountRecords => CryptoGetAccountRecords,
+    CryptoGetProxyStakers => CryptoGetProxyStakers,
+    TransactionGetReceipt => TransactionGetReceipt,
+    TransactionGetRecord => TransactionGetRecord,
+    FileGetContents => FileGetContents,
+    FileGetInfo => FileGetInfo,
+    ContractCallLocal => ContractCallLocal,
+    ContractGetInfo => ContractGetInfo,
+    ContractGetBytecode => ContractGetBytecodeResponse,
+    ConsensusGetTopicInfo => ConsensusGetTopicInfo,
+    TokenGetInfo => TokenGetInfo,
+    TokenGetNftInfo => TokenGetNftInfo,
+    ScheduleGetInfo => ScheduleGetInfo,
+    NetworkGetVersionInfo => NetworkGetVersionInfo,
+}
+
+#[derive(Clone)]
+struct ConsensusNode {
+    node_account_id: AccountId,
+    shared: Arc<Shared>,
+}
+
+impl ConsensusNode {
+    fn record(&self, method: String, transaction_id: Option<TransactionId>) {
+        self.shared.requests.lock().push(MockRequest {
+            node_account_id: self.node_account_id,
+            method,
+            transaction_id,
+        });
+    }
+
+    async fn next_response(&self) -> Result<Option<MockResponse>, tonic::Status> {
+        match self.shared.next_response(self.node_account_id) {
+            Some(response) => resolve(response).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns true if `query` is the client checking that this node is healthy.
+    fn is_ping(&self, query: &services::query::Query) -> bool {
+        use services::crypto_get_account_balance_query::BalanceSource;
+
+        let services::query::Query::CryptogetAccountBalance(query) = query else {
+            return false;
+        };
+
+        query.header.as_ref().map_or(true, |it| it.payment.is_none())
+            && query.balance_source
+                == Some(BalanceSource::AccountId(self.node_account_id.to_protobuf()))
+    }
+
+    async fn transaction(
+        self,
+        method: String,
+        transaction: services::Transaction,
+    ) -> Result<services::TransactionResponse, tonic::Status> {
+        self.record(method.clone(), transaction_id(&transaction));
+
+        let status = match self.next_response().await? {
+            None => Status::Ok,
+            Some(MockResponse::PreCheck(status)) => status,
+            Some(_) => return Err(mismatched(&method)),
+        };
+
+        Ok(services::TransactionResponse { node_transaction_precheck_code: status as i32, cost: 0 })
+    }
+
+    async fn query(
+        self,
+        method: String,
+        query: services::Query,
+    ) -> Result<services::Response, tonic::Status> {
+        use services::response::Response;
+
+        let query =
+            query.query.ok_or_else(|| tonic::Status::invalid_argument("missing `query`"))?;
+
+        let (header, mut response) =
+            query_response(&query).ok_or_else(|| unimplemented(&method))?;
+
+        let response_type = header.map_or(0, |it| it.response_type);
+
+        let scripted =
+            if response_type == services::ResponseType::CostAnswer as i32 || self.is_ping(&query) {
+                None
+            } else {
+                self.record(method.clone(), None);
+                self.next_response().await?
+            };
+
+        match (&mut response, &query) {
+            (Response::TransactionGetReceipt(it), _) => it.receipt = Some(success_receipt()),
+            (
+                Response::TransactionGetRecord(it),
+                services::query::Query::TransactionGetRecord(query),
+            ) => {
+                it.transaction_record = Some(services::TransactionRecord {
+                    receipt: Some(success_receipt()),
+                    transaction_id: query.transaction_id.clone(),
+                    consensus_timestamp: Some(OffsetDateTime::now_utc().to_protobuf()),
+                    ..Default::default()
+                });
+            }
+            _ => {}
+        }
+
+        let status = match scripted {
+            None => Status::Ok,
+            Some(MockResponse::PreCheck(status)) => status,
+            Some(MockResponse::Receipt(receipt)) => {
+                let Response::TransactionGetReceipt(it) = &mut response else {
+                    return Err(mismatched(&method));
+                };
+
+                it.receipt = Some(receipt.to_protobuf());
+                Status::Ok
+            }
+            Some(MockResponse::Record(record)) => {
+                let Response::TransactionGetRecord(it) = &mut response else {
+                    return Err(mismatched(&method));
+                };
+
+                it.transaction_record = Some(record.to_protobuf());
+                Status::Ok
+            }
+            Some(_) => return Err(mismatched(&method)),
+        };
+
+        if let Some(header) = header_mut(&mut response) {
+            *header = Some(services::ResponseHeader {
+                node_transaction_precheck_code: status as i32,
+                response_type,
+                ..Default::default()
+            });
+        }
+
+        Ok(services::Response { response: Some(response) })
+    }
+}
+
+impl Handler for ConsensusNode {
+    fn handle<B>(self, request: http::Request<B>) -> BoxFuture<'static, HttpResponse>
+    where
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        let method = request.uri().path().to_owned();
+        let name = method.rsplit('/').next().unwrap_or_default();
+
+        // every query method is named like a getter, except for `contractCallLocalMethod`.
+        let is_query =
+            name.starts_with("get") || name.contains("Get") || name == "contractCallLocalMethod";
+
+        Box::pin(async move {
+            if is_query {
+                Grpc::new(ProstCodec::<services::Response, services::Query>::default())
+                    .unary(Unary(self, method, ConsensusNode::query), request)
+                    .await
+            } else {
+                Grpc::new(
+                    ProstCodec::<services::TransactionResponse, services::Transaction>::default(),
+                )
+                .unary(Unary(self, method, ConsensusNode::transaction), request)
+                .await
+            }
+        })
+    }
+}
+
+/// A unary method of a [`ConsensusNode`].
+struct Unary<F>(ConsensusNode, String, F);
+
+impl<Req, Resp, Fut, F> UnaryService<Req> for Unary<F>
+where
+    F: Fn(ConsensusNode, String, Req) -> Fut,
+    Fut: std::future::Future<Output = Result<Resp, tonic::Status>> + Send + 'static,
+{
+    type Response = Resp;
+    type Future = BoxFuture<'static, Result<tonic::Response<Resp>, tonic::Status>>;
+
+    fn call(&mut self, request: tonic::Request<Req>) -> Self::Future {
+        let response = (self.2)(self.0.clone(), self.1.clone(), request.into_inner());
+
+        Box::pin(async move { response.await.map(tonic::Response::new) })
+    }
+}
+
+#[derive(Clone)]
+struct MirrorNode {
+    shared: Arc<Shared>,
+}
+
+impl MirrorNode {
+    fn subscribe_topic(
+        &self,
+    ) -> BoxStream<'static, Result<mirror::ConsensusTopicResponse, tonic::Status>> {
+        let items = self.shared.topic_subscriptions.lock().pop_front().unwrap_or_default();
+
+        Box::pin(async_stream::stream! {
+            for (item, sequence_number) in items.into_iter().zip(1..) {
+                match item {
+                    MockTopicItem::Message(message) => yield Ok(mirror::ConsensusTopicResponse {
+                        consensus_timestamp: Some(OffsetDateTime::now_utc().to_protobuf()),
+                        message,
+                        sequence_number,
+                        ..Default::default()
+                    }),
+                    MockTopicItem::Error(code) => {
+                        yield Err(tonic::Status::new(code, "scripted by the mock network"));
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    fn get_nodes(&self) -> BoxStream<'static, Result<services::NodeAddress, tonic::Status>> {
+        let addresses: Vec<_> = self
+            .shared
+            .nodes
+            .lock()
+            .iter()
+            .zip(0..)
+            .map(|((node_account_id, address), node_id)| {
+                Ok(services::NodeAddress {
+                    node_id,
+                    node_account_id: Some(node_account_id.to_protobuf()),
+                    service_endpoint: Vec::from([services::ServiceEndpoint {
+                        ip_address_v4: Vec::from([127, 0, 0, 1]),
+                        port: i32::from(address.port()),
+                        domain_name: String::new(),
+                    }]),
+                    ..Default::default()
+                })
+            })
+            .collect();
+
+        Box::pin(futures_util::stream::iter(addresses))
+    }
+}
+
+impl Handler for MirrorNode {
+    fn handle<B>(self, request: http::Request<B>) -> BoxFuture<'static, HttpResponse>
+    where
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        Box::pin(async move {
+            match request.uri().path() {
+                "/com.hedera.mirror.api.proto.ConsensusService/subscribeTopic" => {
+                    Grpc::new(ProstCodec::<
+                        mirror::ConsensusTopicResponse,
+                        mirror::ConsensusTopicQuery,
+                    >::default())
+                    .server_streaming(Streaming(self, MirrorNode::subscribe_topic), request)
+                    .await
+                }
+                "/com.hedera.mirror.api.proto.NetworkService/getNodes" => {
+                    Grpc::new(
+                        ProstCodec::<services::NodeAddress, mirror::AddressBookQuery>::default(),
+                    )
+                    .server_streaming(Streaming(self, MirrorNode::get_nodes), request)
+                    .await
+                }
+                method => unimplemented(method).to_http(),
+            }
+        })
+    }
+}
+
+/// A server streaming method of a [`MirrorNode`], the mock ignores the request itself.
+struct Streaming<F>(MirrorNode, F);
+
+impl<Req, Resp, F> ServerStreamingService<Req> for Streaming<F>
+where
+    F: Fn(&MirrorNode) -> BoxStream<'static, Result<Resp, tonic::Status>>,
+    Resp: Send + 'static,
+{
+    type Response = Resp;
+    type ResponseStream = BoxStream<'static, Result<Resp, tonic::Status>>;
+    type Future = BoxFuture<'static, Result<tonic::Response<Self::ResponseStream>, tonic::Status>>;
+
+    fn call(&mut self, _request: tonic::Request<Req>) -> Self::Future {
+        let stream = (self.1)(&self.0);
+
+        Box::pin(std::future::ready(Ok(tonic::Response::new(stream))))
+    }
+}
@@ -0,0 +1,252 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::circuit_breaker::CircuitState;
+use crate::AccountId;
+
+/// A snapshot of the health and performance of the network, as seen by a [`Client`](crate::Client).
+///
+/// Returned by [`Client::network_stats`](crate::Client::network_stats).
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct NetworkStats {
+    /// Stats for each node currently in the client's network.
+    pub nodes: HashMap<AccountId, NodeStats>,
+
+    /// Stats for each type of request the client has executed, keyed by the name of the request type.
+    ///
+    /// The names are meant for diagnostics only, their exact values aren't stable.
+    pub requests: HashMap<&'static str, RequestStats>,
+}
+
+/// Stats for a single node.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct NodeStats {
+    /// The number of attempts against this node that completed the request.
+    pub successes: u64,
+
+    /// The number of attempts against this node that didn't complete the request,
+    /// for any reason (including gRPC errors, timeouts and pre-check failures).
+    pub failures: u64,
+
+    /// The time it took the node to respond to each gRPC request.
+    pub latency: LatencyHistogram,
+
+    /// The node's backoff, if it's currently considered unhealthy.
+    pub backoff: Option<NodeBackoffStats>,
+
+    /// The state of the node's circuit breaker.
+    ///
+    /// This is always [`CircuitState::Closed`] unless a circuit breaker has been configured.
+    pub circuit: CircuitState,
+
+    /// The error from the most recent failed attempt against this node.
+    pub last_error: Option<String>,
+}
+
+/// The backoff state of an unhealthy node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NodeBackoffStats {
+    /// The backoff the node was given the last time it was marked unhealthy.
+    pub current_backoff: Duration,
+
+    /// The number of times in a row that the node has been marked unhealthy.
+    pub attempts: usize,
+
+    /// How long until the node will be tried again, zero if it already can be.
+    pub remaining: Duration,
+}
+
+/// Stats for a single type of request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RequestStats {
+    /// The number of times a request of this type was executed.
+    pub executions: u64,
+
+    /// The number of attempts made after the first attempt of each execution.
+    pub retries: u64,
+
+    /// The number of timeouts, both for single gRPC requests and for whole executions.
+    pub timeouts: u64,
+}
+
+const LATENCY_BUCKETS: [Duration; 10] = [
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_millis(2500),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+];
+
+/// A histogram of response latencies with fixed buckets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    counts: [u64; LATENCY_BUCKETS.len() + 1],
+    total: Duration,
+}
+
+impl LatencyHistogram {
+    /// The (inclusive) upper bounds of each bucket, the final bucket has no upper bound.
+    pub const BUCKETS: [Duration; 10] = LATENCY_BUCKETS;
+
+    pub(crate) fn record(&mut self, latency: Duration) {
+        let index =
+            Self::BUCKETS.iter().position(|bound| latency <= *bound).unwrap_or(Self::BUCKETS.len());
+
+        self.counts[index] += 1;
+        self.total += latency;
+    }
+
+    /// Returns each bucket's upper bound along with the number of responses in it.
+    ///
+    /// The final bucket has an upper bound of `None`.
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        Self::BUCKETS.iter().copied().map(Some).chain([None]).zip(self.counts.iter().copied())
+    }
+
+    /// Returns the number of responses recorded.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the mean latency, or `None` if no responses have been recorded.
+    #[must_use]
+    pub fn mean(&self) -> Option<Duration> {
+        let count = u32::try_from(self.count()).ok().filter(|it| *it != 0)?;
+
+        Some(self.total / count)
+    }
+}
+
+/// Counters for a single node, these stick around across network updates.
+#[derive(Default)]
+pub(crate) struct NodeMetrics {
+    successes: u64,
+    failures: u64,
+    latency: LatencyHistogram,
+    last_error: Option<String>,
+}
+
+impl NodeMetrics {
+    pub(crate) fn record_latency(&mut self, latency: Duration) {
+        self.latency.record(latency);
+    }
+
+    pub(crate) fn mean_latency(&self) -> Option<Duration> {
+        self.latency.mean()
+    }
+
+    pub(crate) fn record_success(&mut self) {
+        self.successes += 1;
+    }
+
+    pub(crate) fn record_failure(&mut self, error: &crate::Error) {
+        self.failures += 1;
+        self.last_error = Some(error.to_string());
+    }
+
+    pub(crate) fn snapshot(
+        &self,
+        backoff: Option<NodeBackoffStats>,
+        circuit: CircuitState,
+    ) -> NodeStats {
+        NodeStats {
+            successes: self.successes,
+            failures: self.failures,
+            latency: self.latency.clone(),
+            backoff,
+            circuit,
+            last_error: self.last_error.clone(),
+        }
+    }
+}
+
+/// Counters for every type of request a client has executed.
+#[derive(Default)]
+pub(crate) struct RequestMetrics(Mutex<HashMap<&'static str, RequestStats>>);
+
+impl RequestMetrics {
+    fn update(&self, request_type: &'static str, f: impl FnOnce(&mut RequestStats)) {
+        f(self.0.lock().entry(request_type).or_default());
+    }
+
+    pub(crate) fn record_execution(&self, request_type: &'static str) {
+        self.update(request_type, |it| it.executions += 1);
+    }
+
+    pub(crate) fn record_retry(&self, request_type: &'static str) {
+        self.update(request_type, |it| it.retries += 1);
+    }
+
+    pub(crate) fn record_timeout(&self, request_type: &'static str) {
+        self.update(request_type, |it| it.timeouts += 1);
+    }
+
+    pub(crate) fn snapshot(&self) -> HashMap<&'static str, RequestStats> {
+        self.0.lock().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::LatencyHistogram;
+
+    #[test]
+    fn latency_histogram() {
+        let mut histogram = LatencyHistogram::default();
+
+        assert_eq!(histogram.mean(), None);
+
+        histogram.record(Duration::from_millis(5));
+        histogram.record(Duration::from_millis(10));
+        histogram.record(Duration::from_millis(75));
+        histogram.record(Duration::from_secs(30));
+
+        assert_eq!(histogram.count(), 4);
+        assert_eq!(histogram.mean(), Some(Duration::from_micros(7_522_500)));
+
+        let buckets: Vec<_> = histogram.buckets().filter(|it| it.1 != 0).collect();
+
+        assert_eq!(
+            buckets,
+            [
+                (Some(Duration::from_millis(10)), 2),
+                (Some(Duration::from_millis(100)), 1),
+                (None, 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,297 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::time::Duration;
+
+use rand::seq::SliceRandom;
+use rand::{
+    thread_rng,
+    Rng,
+};
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::AccountId;
+
+/// A node that a [`NodeSelector`] can choose, along with what the client knows about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NodeCandidate {
+    /// The account ID of the node.
+    pub node_account_id: AccountId,
+
+    /// The mean time the node has taken to respond, if it has ever responded.
+    pub mean_latency: Option<Duration>,
+}
+
+/// Chooses which nodes a [`Client`](crate::Client) tries when executing a request, and in what order.
+///
+/// Set with [`Client::set_node_selector`](crate::Client::set_node_selector),
+/// the default is [`RandomNodeSelector`].
+pub trait NodeSelector: Send + Sync {
+    /// Returns the indexes of the `candidates` to try, in the order they should be tried.
+    ///
+    /// When `explicit` is `true` the candidates are the nodes a request was explicitly given
+    /// (for instance with `node_account_ids`), and usually all of them should be returned.
+    /// Otherwise the candidates are every healthy node in the network and only some of them should be returned.
+    ///
+    /// Out of range and duplicate indexes are ignored.
+    /// If no nodes are returned the client backs off and asks again.
+    fn select(&self, candidates: &[NodeCandidate], explicit: bool) -> Vec<usize>;
+}
+
+/// The number of nodes the built-in selectors return, every node if `explicit`,
+/// otherwise a third of the nodes (rounded up).
+fn select_amount(len: usize, explicit: bool) -> usize {
+    match explicit {
+        true => len,
+        false => (len + 2) / 3,
+    }
+}
+
+/// Tries a random selection of nodes, this is the default.
+///
+/// When the nodes aren't explicit a random third of them are tried.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RandomNodeSelector;
+
+impl NodeSelector for RandomNodeSelector {
+    fn select(&self, candidates: &[NodeCandidate], explicit: bool) -> Vec<usize> {
+        let mut indexes: Vec<_> = (0..candidates.len()).collect();
+
+        let amount = select_amount(indexes.len(), explicit);
+
+        let (shuffled, _) = indexes.partial_shuffle(&mut thread_rng(), amount);
+
+        shuffled.to_vec()
+    }
+}
+
+/// Tries nodes in a random order that favors nodes which respond faster.
+///
+/// A node's chance of going first is proportional to the inverse of its mean latency.
+/// Nodes that haven't responded yet are treated as being as fast as the fastest known node, so that they get tried.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LatencyWeightedNodeSelector;
+
+impl NodeSelector for LatencyWeightedNodeSelector {
+    fn select(&self, candidates: &[NodeCandidate], explicit: bool) -> Vec<usize> {
+        let fastest = candidates.iter().filter_map(|it| it.mean_latency).min();
+
+        let mut rng = thread_rng();
+
+        // weighted sampling without replacement (Efraimidis & Spirakis):
+        // each node gets a key of `u^(1/weight)` and the largest keys win,
+        // with `weight = 1/latency` that's `u^latency`.
+        let mut keyed: Vec<_> = candidates
+            .iter()
+            .enumerate()
+            .map(|(index, candidate)| {
+                let latency = candidate
+                    .mean_latency
+                    .or(fastest)
+                    .map_or(1.0, |it| it.as_secs_f64().max(0.001));
+
+                (rng.gen::<f64>().powf(latency), index)
+            })
+            .collect();
+
+        keyed.sort_by(|lhs, rhs| rhs.0.total_cmp(&lhs.0));
+
+        let amount = select_amount(keyed.len(), explicit);
+
+        keyed.into_iter().take(amount).map(|(_, index)| index).collect()
+    }
+}
+
+/// Tries nodes in turn, each selection starts one node further along than the previous one.
+///
+/// Since only healthy nodes are candidates the rotation is approximate when node health changes.
+#[derive(Debug, Default)]
+pub struct RoundRobinNodeSelector {
+    next: AtomicUsize,
+}
+
+impl RoundRobinNodeSelector {
+    /// Create a new `RoundRobinNodeSelector`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl NodeSelector for RoundRobinNodeSelector {
+    fn select(&self, candidates: &[NodeCandidate], explicit: bool) -> Vec<usize> {
+        let len = candidates.len();
+
+        if len == 0 {
+            return Vec::new();
+        }
+
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % len;
+
+        (0..select_amount(len, explicit)).map(|offset| (start + offset) % len).collect()
+    }
+}
+
+/// Always tries the preferred nodes first (in the order given), then falls back to another selector.
+///
+/// Preferred nodes are only tried while they're healthy, or when they're explicitly given.
+#[derive(Clone)]
+pub struct StickyNodeSelector {
+    preferred: Vec<AccountId>,
+    fallback: Arc<dyn NodeSelector>,
+}
+
+impl StickyNodeSelector {
+    /// Create a new `StickyNodeSelector` that falls back to a [`RandomNodeSelector`].
+    #[must_use]
+    pub fn new(preferred: Vec<AccountId>) -> Self {
+        Self::with_fallback(preferred, RandomNodeSelector)
+    }
+
+    /// Create a new `StickyNodeSelector` that falls back to `fallback` for the non-preferred nodes.
+    #[must_use]
+    pub fn with_fallback<S: NodeSelector + 'static>(
+        preferred: Vec<AccountId>,
+        fallback: S,
+    ) -> Self {
+        Self { preferred, fallback: Arc::new(fallback).unsize(Coercion!(to dyn NodeSelector)) }
+    }
+
+    /// Returns the preferred nodes.
+    #[must_use]
+    pub fn preferred(&self) -> &[AccountId] {
+        &self.preferred
+    }
+}
+
+impl fmt::Debug for StickyNodeSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StickyNodeSelector")
+            .field("preferred", &self.preferred)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NodeSelector for StickyNodeSelector {
+    fn select(&self, candidates: &[NodeCandidate], explicit: bool) -> Vec<usize> {
+        let mut selected: Vec<usize> = Vec::new();
+
+        for id in &self.preferred {
+            let index = candidates.iter().position(|it| it.node_account_id == *id);
+
+            if let Some(index) = index.filter(|it| !selected.contains(it)) {
+                selected.push(index);
+            }
+        }
+
+        let (rest_indexes, rest): (Vec<_>, Vec<_>) =
+            candidates.iter().enumerate().filter(|(index, _)| !selected.contains(index)).unzip();
+
+        let fallback = self.fallback.select(&rest, explicit);
+
+        selected.extend(fallback.into_iter().filter_map(|it| rest_indexes.get(it).copied()));
+
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        LatencyWeightedNodeSelector,
+        NodeCandidate,
+        NodeSelector,
+        RandomNodeSelector,
+        RoundRobinNodeSelector,
+        StickyNodeSelector,
+    };
+    use crate::AccountId;
+
+    fn candidates(count: u64) -> Vec<NodeCandidate> {
+        (3..count + 3)
+            .map(|num| NodeCandidate { node_account_id: AccountId::from(num), mean_latency: None })
+            .collect()
+    }
+
+    fn sorted(mut indexes: Vec<usize>) -> Vec<usize> {
+        indexes.sort_unstable();
+        indexes
+    }
+
+    #[test]
+    fn random_selects_a_third() {
+        let candidates = candidates(7);
+
+        let selected = RandomNodeSelector.select(&candidates, false);
+        assert_eq!(selected.len(), 3);
+
+        let selected = RandomNodeSelector.select(&candidates, true);
+        assert_eq!(sorted(selected), [0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn latency_weighted_selects_every_explicit_node() {
+        let mut candidates = candidates(4);
+        candidates[1].mean_latency = Some(Duration::from_millis(20));
+        candidates[2].mean_latency = Some(Duration::from_secs(2));
+
+        let selected = LatencyWeightedNodeSelector.select(&candidates, true);
+        assert_eq!(sorted(selected), [0, 1, 2, 3]);
+
+        let selected = LatencyWeightedNodeSelector.select(&candidates, false);
+        assert_eq!(selected.len(), 2);
+    }
+
+    #[test]
+    fn round_robin_rotates() {
+        let candidates = candidates(3);
+        let selector = RoundRobinNodeSelector::new();
+
+        assert_eq!(selector.select(&candidates, true), [0, 1, 2]);
+        assert_eq!(selector.select(&candidates, true), [1, 2, 0]);
+        assert_eq!(selector.select(&candidates, false), [2]);
+        assert_eq!(selector.select(&candidates, false), [0]);
+    }
+
+    #[test]
+    fn sticky_prefers_nodes() {
+        let candidates = candidates(6);
+        let selector = StickyNodeSelector::with_fallback(
+            Vec::from([AccountId::from(5), AccountId::from(100), AccountId::from(3)]),
+            RoundRobinNodeSelector::new(),
+        );
+
+        // preferred nodes first, then the fallback picks from the remaining nodes (4, 6, 7, 8).
+        assert_eq!(selector.select(&candidates, false), [2, 0, 1, 3]);
+        assert_eq!(selector.select(&candidates, true), [2, 0, 3, 4, 5, 1]);
+    }
+}
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 
@@ -74,11 +75,11 @@ impl Execute for PingQuery {
         false
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&crate::TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        _transaction_id: Option<&'a crate::TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         const HEADER: services::QueryHeader = services::QueryHeader {
             payment: None,
             response_type: services::ResponseType::AnswerOnly as i32,
@@ -99,7 +100,7 @@ impl Execute for PingQuery {
             )),
         };
 
-        Ok((query, ()))
+        Box::pin(std::future::ready(Ok((query, ()))))
     }
 
     fn execute(
@@ -18,8 +18,10 @@
  * ‍
  */
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
+use triomphe::Arc;
 
 use crate::entity_id::ValidateChecksums;
 use crate::execute::{
@@ -34,6 +36,7 @@ use crate::{
     Client,
     Hbar,
     Query,
+    RetryPolicy,
     Tinybar,
     TransactionId,
 };
@@ -81,17 +84,21 @@ where
         None
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&TransactionId>,
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        Execute::retry_policy(self.0)
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        _transaction_id: Option<&'a TransactionId>,
         _node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         let header = services::QueryHeader {
             response_type: services::ResponseType::CostAnswer as i32,
             payment: None,
         };
 
-        Ok((self.0.data.to_query_protobuf(header), ()))
+        Box::pin(std::future::ready(Ok((self.0.data.to_query_protobuf(header), ()))))
     }
 
     fn execute(
@@ -20,8 +20,10 @@
 
 use std::fmt::Debug;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
+use triomphe::Arc;
 
 use crate::entity_id::ValidateChecksums;
 use crate::execute::Execute;
@@ -29,13 +31,17 @@ use crate::query::{
     AnyQueryData,
     ToQueryProtobuf,
 };
+#[cfg(feature = "serde")]
+use crate::ContractAbi;
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    ContractRevert,
     Error,
     FromProtobuf,
     Hbar,
     Query,
+    RetryPolicy,
     Status,
     TransactionId,
 };
@@ -73,6 +79,12 @@ pub trait QueryExecute:
         None
     }
 
+    /// Returns the ABI used to decode the custom errors of a reverted contract call, if one was given.
+    #[cfg(feature = "serde")]
+    fn contract_abi(&self) -> Option<&ContractAbi> {
+        None
+    }
+
     fn make_response(
         &self,
         response: services::response::Response,
@@ -124,20 +136,26 @@ where
         self.data.should_retry(response)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
-        node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
-        let payment = if self.data.is_payment_required() {
-            Some(self.payment.make_request(transaction_id, node_account_id)?.0)
-        } else {
-            None
-        };
-
-        let header = services::QueryHeader { response_type: 0, payment };
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        Execute::retry_policy(&self.payment)
+    }
 
-        Ok((self.data.to_query_protobuf(header), ()))
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
+        node_account_id: AccountId,
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
+        Box::pin(async move {
+            let payment = if self.data.is_payment_required() {
+                Some(self.payment.make_request(transaction_id, node_account_id).await?.0)
+            } else {
+                None
+            };
+
+            let header = services::QueryHeader { response_type: 0, payment };
+
+            Ok((self.data.to_query_protobuf(header), ()))
+        })
     }
 
     fn execute(
@@ -162,8 +180,27 @@ where
         &self,
         status: crate::Status,
         transaction_id: Option<&TransactionId>,
-        _response: Self::GrpcResponse,
+        response: Self::GrpcResponse,
     ) -> crate::Error {
+        let revert = match status {
+            Status::ContractRevertExecuted => ContractRevert::from_response(&response),
+            _ => None,
+        };
+
+        #[cfg(feature = "serde")]
+        let revert = match (revert, self.data.contract_abi()) {
+            (Some(revert), Some(abi)) => Some(revert.with_abi(abi)),
+            (revert, _) => revert,
+        };
+
+        if let Some(revert) = revert {
+            return crate::Error::ContractRevert {
+                status,
+                transaction_id: transaction_id.copied().map(Box::new),
+                revert: Box::new(revert),
+            };
+        }
+
         if let Some(transaction_id) = self.data.transaction_id() {
             crate::Error::QueryPreCheckStatus { status, transaction_id: Box::new(transaction_id) }
         } else if let Some(transaction_id) = transaction_id {
@@ -29,6 +29,7 @@ use crate::{
     Client,
     Error,
     Hbar,
+    RetryPolicy,
     TransactionId,
     TransactionReceiptQuery,
 };
@@ -195,6 +196,18 @@ where
         self
     }
 
+    /// Returns the retry policy this query uses instead of the one on the client, if any.
+    #[must_use]
+    pub fn get_retry_policy(&self) -> Option<&dyn RetryPolicy> {
+        self.payment.get_retry_policy()
+    }
+
+    /// Sets the [`RetryPolicy`] to use when executing this query, instead of the one on the client.
+    pub fn retry_policy<P: RetryPolicy + 'static>(&mut self, policy: P) -> &mut Self {
+        self.payment.retry_policy(policy);
+        self
+    }
+
     /// Fetch the cost of this query.
     pub async fn get_cost(&self, client: &Client) -> crate::Result<Hbar> {
         self.get_cost_with_optional_timeout(client, None).await
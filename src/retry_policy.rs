@@ -0,0 +1,252 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{
+    AccountId,
+    Status,
+};
+
+/// What to do after an attempt at executing a request fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryDecision {
+    /// Try the same node again, immediately.
+    ///
+    /// Once the client's [`max_attempts`](crate::Client::max_attempts) is reached this is treated as
+    /// [`RetryNextNode`](Self::RetryNextNode), so that a policy can't retry a node forever.
+    RetrySameNode,
+
+    /// Try the next node, immediately.
+    RetryNextNode,
+
+    /// Back off, and then try again.
+    Backoff,
+
+    /// Stop, and return the error to the caller.
+    Fail,
+}
+
+/// A failed attempt that a [`RetryPolicy`] is deciding about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FailedAttempt {
+    /// The name of the type being executed, such as `hedera::Transaction<...>`.
+    ///
+    /// This is meant for diagnostics only, the exact value isn't stable.
+    pub request_type: &'static str,
+
+    /// The account ID of the node the attempt was made against.
+    pub node_account_id: AccountId,
+
+    /// The attempt number, starting at 1 for the first attempt of each execution.
+    pub attempt: usize,
+
+    /// The pre-check status the node responded with, if it responded.
+    pub status: Option<Status>,
+
+    /// The gRPC code the request failed with, if the gRPC request itself failed.
+    ///
+    /// Exceeding the client's gRPC timeout is reported as [`DeadlineExceeded`](tonic::Code::DeadlineExceeded).
+    pub grpc_code: Option<tonic::Code>,
+
+    /// What the client would do without a policy.
+    ///
+    /// This takes things such as the request type and whether the transaction ID can be regenerated into account.
+    pub default_decision: RetryDecision,
+}
+
+/// Decides what a [`Client`](crate::Client) does after an attempt at executing a request fails.
+///
+/// Policies are asked about attempts that failed with a pre-check status other than `OK`, or a gRPC error.
+/// Anything else (such as a query that's still waiting for a receipt) isn't up to the policy.
+///
+/// Set with [`Client::set_retry_policy`](crate::Client::set_retry_policy), or per request with
+/// [`Transaction::retry_policy`](crate::Transaction::retry_policy) and [`Query::retry_policy`](crate::Query::retry_policy).
+/// The default is [`DefaultRetryPolicy`].
+///
+/// Any `Fn(&FailedAttempt) -> RetryDecision` is a policy, for instance to fail fast on anything:
+/// ```
+/// # fn f(client: &hedera::Client) {
+/// use hedera::{FailedAttempt, RetryDecision};
+///
+/// client.set_retry_policy(|_: &FailedAttempt| RetryDecision::Fail);
+/// # }
+/// ```
+pub trait RetryPolicy: Send + Sync {
+    /// Returns what to do about `attempt`.
+    fn decide(&self, attempt: &FailedAttempt) -> RetryDecision;
+}
+
+impl<F> RetryPolicy for F
+where
+    F: Fn(&FailedAttempt) -> RetryDecision + Send + Sync,
+{
+    fn decide(&self, attempt: &FailedAttempt) -> RetryDecision {
+        self(attempt)
+    }
+}
+
+impl fmt::Debug for dyn RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy").finish_non_exhaustive()
+    }
+}
+
+/// Always does what the client would do without a policy.
+///
+/// `BUSY` and `PLATFORM_NOT_ACTIVE` responses, along with unavailable nodes, are retried on the next node,
+/// an expired transaction is retried with a new transaction ID when it can be regenerated,
+/// and anything else fails unless the request says otherwise.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultRetryPolicy;
+
+impl RetryPolicy for DefaultRetryPolicy {
+    fn decide(&self, attempt: &FailedAttempt) -> RetryDecision {
+        attempt.default_decision
+    }
+}
+
+/// Overrides the decision for specific pre-check statuses and gRPC codes.
+///
+/// Anything that isn't overridden uses the [`fallback`](Self::fallback) decision,
+/// or the client's default decision if there isn't one.
+///
+/// # Examples
+/// ```
+/// use hedera::{RetryDecision, Status, StatusRetryPolicy};
+///
+/// // fail fast on anything, except for throttling.
+/// let policy = StatusRetryPolicy::new()
+///     .status(Status::ThrottledAtConsensus, RetryDecision::Backoff)
+///     .fallback(RetryDecision::Fail);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StatusRetryPolicy {
+    statuses: HashMap<Status, RetryDecision>,
+    grpc_codes: HashMap<tonic::Code, RetryDecision>,
+    fallback: Option<RetryDecision>,
+}
+
+impl StatusRetryPolicy {
+    /// Create a new `StatusRetryPolicy` that doesn't override anything.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes attempts that fail with the pre-check `status` use `decision`.
+    #[must_use]
+    pub fn status(mut self, status: Status, decision: RetryDecision) -> Self {
+        self.statuses.insert(status, decision);
+
+        self
+    }
+
+    /// Makes attempts that fail with the gRPC `code` use `decision`.
+    #[must_use]
+    pub fn grpc_code(mut self, code: tonic::Code, decision: RetryDecision) -> Self {
+        self.grpc_codes.insert(code, decision);
+
+        self
+    }
+
+    /// Makes every attempt that isn't overridden otherwise use `decision`.
+    #[must_use]
+    pub fn fallback(mut self, decision: RetryDecision) -> Self {
+        self.fallback = Some(decision);
+
+        self
+    }
+}
+
+impl RetryPolicy for StatusRetryPolicy {
+    fn decide(&self, attempt: &FailedAttempt) -> RetryDecision {
+        let overridden = match (attempt.status, attempt.grpc_code) {
+            (Some(status), _) => self.statuses.get(&status),
+            (_, Some(code)) => self.grpc_codes.get(&code),
+            (None, None) => None,
+        };
+
+        overridden.copied().or(self.fallback).unwrap_or(attempt.default_decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FailedAttempt,
+        RetryDecision,
+        RetryPolicy,
+        StatusRetryPolicy,
+    };
+    use crate::{
+        AccountId,
+        Status,
+    };
+
+    fn attempt(status: Option<Status>, grpc_code: Option<tonic::Code>) -> FailedAttempt {
+        FailedAttempt {
+            request_type: "test",
+            node_account_id: AccountId::new(0, 0, 3),
+            attempt: 1,
+            status,
+            grpc_code,
+            default_decision: RetryDecision::RetryNextNode,
+        }
+    }
+
+    #[test]
+    fn status_policy_overrides() {
+        let policy = StatusRetryPolicy::new()
+            .status(Status::ThrottledAtConsensus, RetryDecision::Backoff)
+            .grpc_code(tonic::Code::Unavailable, RetryDecision::RetrySameNode);
+
+        assert_eq!(
+            policy.decide(&attempt(Some(Status::ThrottledAtConsensus), None)),
+            RetryDecision::Backoff
+        );
+        assert_eq!(
+            policy.decide(&attempt(None, Some(tonic::Code::Unavailable))),
+            RetryDecision::RetrySameNode
+        );
+
+        // not overridden.
+        assert_eq!(policy.decide(&attempt(Some(Status::Busy), None)), RetryDecision::RetryNextNode);
+    }
+
+    #[test]
+    fn status_policy_fallback() {
+        let policy = StatusRetryPolicy::new()
+            .status(Status::ThrottledAtConsensus, RetryDecision::Backoff)
+            .fallback(RetryDecision::Fail);
+
+        assert_eq!(
+            policy.decide(&attempt(Some(Status::ThrottledAtConsensus), None)),
+            RetryDecision::Backoff
+        );
+        assert_eq!(policy.decide(&attempt(Some(Status::Busy), None)), RetryDecision::Fail);
+        assert_eq!(
+            policy.decide(&attempt(None, Some(tonic::Code::Unavailable))),
+            RetryDecision::Fail
+        );
+    }
+}
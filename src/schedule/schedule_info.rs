@@ -107,7 +107,9 @@ impl ScheduleInfo {
                 transaction_id: Some(self.scheduled_transaction_id),
                 operator: None,
                 is_frozen: true,
+                is_frozen_offline: false,
                 regenerate_transaction_id: Some(false),
+                retry_policy: None,
             },
             Vec::new(),
         ))
@@ -20,6 +20,9 @@
 
 use std::fmt;
 
+use futures_core::future::BoxFuture;
+use futures_core::Future;
+use futures_util::FutureExt;
 use triomphe::Arc;
 use unsize::{
     CoerceUnsize,
@@ -27,10 +30,71 @@ use unsize::{
 };
 
 use crate::{
+    Error,
     PrivateKey,
     PublicKey,
 };
 
+/// Something that can produce signatures for a single [`PublicKey`].
+///
+/// This allows keys that aren't held in memory (for example keys kept in an HSM or a KMS)
+/// to be used as the operator of a [`Client`](crate::Client), or as an extra signer for a [`Transaction`](crate::Transaction).
+///
+/// A signer can take as long as it needs when a transaction is executed, but the transaction methods
+/// that aren't `async` need every signer of the transaction to complete immediately (like a [`PrivateKey`] does),
+/// and fail with [`Error::Signature`] otherwise:
+/// - [`Transaction::to_bytes`](crate::Transaction::to_bytes)
+/// - [`Transaction::get_transaction_hash`](crate::Transaction::get_transaction_hash)
+///   and [`Transaction::get_transaction_hash_per_node`](crate::Transaction::get_transaction_hash_per_node)
+/// - [`Transaction::signatures`](crate::Transaction::signatures)
+/// - [`Transaction::merge`](crate::Transaction::merge)
+/// - [`Transaction::add_signature`](crate::Transaction::add_signature)
+///   and [`Transaction::try_add_signature`](crate::Transaction::try_add_signature)
+/// - [`Key::is_satisfied_by`](crate::Key::is_satisfied_by)
+///
+/// # Examples
+/// ```
+/// use futures_core::future::BoxFuture;
+/// use hedera::{PrivateKey, PublicKey, Signer};
+///
+/// struct RemoteSigner {
+///     // stand-in for a key that lives somewhere else.
+///     key: PrivateKey,
+/// }
+///
+/// impl Signer for RemoteSigner {
+///     fn public_key(&self) -> PublicKey {
+///         self.key.public_key()
+///     }
+///
+///     fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, hedera::Result<Vec<u8>>> {
+///         Box::pin(async move { Ok(self.key.sign(message)) })
+///     }
+/// }
+/// ```
+pub trait Signer: Send + Sync {
+    /// Returns the public key that signatures from this signer can be verified with.
+    ///
+    /// This should be cheap, it's called more often than [`sign`](Self::sign).
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the given `message`.
+    ///
+    /// # Errors
+    /// Any error that prevents the message from being signed, conventionally [`Error::Signature`].
+    fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>>;
+}
+
+impl Signer for PrivateKey {
+    fn public_key(&self) -> PublicKey {
+        PrivateKey::public_key(self)
+    }
+
+    fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>> {
+        Box::pin(std::future::ready(Ok(PrivateKey::sign(self, message))))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum AnySigner {
     PrivateKey(PrivateKey),
@@ -46,6 +110,8 @@ pub(crate) enum AnySigner {
     // but we can't do that because trait aliases don't exist.
     #[allow(clippy::type_complexity)]
     Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>),
+    // the `Arc` here exists for the same reasons as the one in `Arbitrary`.
+    Signer(Arc<dyn Signer>),
 }
 
 impl AnySigner {
@@ -58,6 +124,10 @@ impl AnySigner {
             Arc::new(signer).unsize(Coercion!(to dyn Fn(&[u8]) -> Vec<u8> + Send + Sync)),
         )
     }
+
+    pub(crate) fn signer<S: Signer + 'static>(signer: S) -> Self {
+        Self::Signer(Arc::new(signer).unsize(Coercion!(to dyn Signer)))
+    }
 }
 
 impl fmt::Debug for AnySigner {
@@ -67,6 +137,7 @@ impl fmt::Debug for AnySigner {
             Self::Arbitrary(arg0, _) => {
                 f.debug_tuple("Arbitrary").field(arg0).field(&"Fn").finish()
             }
+            Self::Signer(it) => f.debug_tuple("Signer").field(&it.public_key()).finish(),
         }
     }
 }
@@ -77,17 +148,56 @@ impl AnySigner {
         match self {
             AnySigner::PrivateKey(it) => it.public_key(),
             AnySigner::Arbitrary(it, _) => **it,
+            AnySigner::Signer(it) => it.public_key(),
         }
     }
 
-    pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+    pub(crate) async fn sign(&self, message: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
         match self {
-            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
+            AnySigner::PrivateKey(it) => Ok((it.public_key(), it.sign(message))),
             AnySigner::Arbitrary(public, signer) => {
                 let bytes = signer(message);
 
-                (**public, bytes)
+                Ok((**public, bytes))
             }
+            AnySigner::Signer(signer) => Ok((signer.public_key(), signer.sign(message).await?)),
         }
     }
 }
+
+/// Runs a signing future to completion *without* waiting.
+///
+/// This is for the synchronous APIs (such as `Transaction::to_bytes`) which need signatures,
+/// anything other than an asynchronous [`Signer`] will always complete immediately.
+///
+/// # Errors
+/// - [`Error::Signature`] if `fut` doesn't complete immediately.
+/// - Any error `fut` itself returns.
+pub(crate) fn sign_now<T>(fut: impl Future<Output = crate::Result<T>>) -> crate::Result<T> {
+    fut.now_or_never().unwrap_or_else(|| {
+        Err(Error::signature(
+            "a signer didn't complete immediately; asynchronous signers can only be used when executing",
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sign_now;
+    use crate::Error;
+
+    #[test]
+    fn sign_now_ready() {
+        assert_eq!(sign_now(async { crate::Result::Ok(5) }).unwrap(), 5);
+    }
+
+    #[test]
+    fn sign_now_pending() {
+        let res = sign_now(async {
+            std::future::pending::<()>().await;
+            crate::Result::Ok(())
+        });
+
+        assert!(matches!(res, Err(Error::Signature(_))));
+    }
+}
@@ -33,6 +33,7 @@ use crate::{
     Client,
     PrivateKey,
     PublicKey,
+    Signer,
     TransactionResponse,
 };
 
@@ -162,6 +163,15 @@ impl TokenRejectFlow {
         self
     }
 
+    /// Sets the [`Signer`] for use in the ``TokenRejectTransaction``
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.token_reject_data.signer = Some(AnySigner::signer(signer));
+
+        self
+    }
+
     /// Set the operator that this transaction will be signed with.
     pub fn sign_with_operator(&mut self, client: &Client) -> &mut Self {
         // todo: proper error
@@ -881,7 +881,9 @@ impl AnyTransaction {
                 )?)?),
                 operator: None,
                 is_frozen: true,
+                is_frozen_offline: false,
                 regenerate_transaction_id: Some(false),
+                retry_policy: None,
             },
             signers: Vec::new(),
             sources: None,
@@ -1146,7 +1148,9 @@ macro_rules! impl_cast_any {
                             transaction_id: transaction.body.transaction_id,
                             operator: transaction.body.operator,
                             is_frozen: transaction.body.is_frozen,
+                            is_frozen_offline: transaction.body.is_frozen_offline,
                             regenerate_transaction_id: transaction.body.regenerate_transaction_id,
+                            retry_policy: transaction.body.retry_policy,
                         },
                         signers: transaction.signers,
                         sources: transaction.sources,
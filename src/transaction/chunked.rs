@@ -1,8 +1,10 @@
 use std::cmp;
 use std::num::NonZeroUsize;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
+use triomphe::Arc;
 
 use super::{
     TransactionData,
@@ -16,6 +18,7 @@ use crate::{
     BoxGrpcFuture,
     Error,
     Hbar,
+    RetryPolicy,
     Transaction,
     TransactionHash,
     TransactionId,
@@ -155,22 +158,30 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        self.transaction.body.retry_policy.clone()
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         self.transaction.operator_account_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo::initial(
-            self.total_chunks,
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            self.transaction
+                .make_request_inner(&ChunkInfo::initial(
+                    self.total_chunks,
+                    *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                    node_account_id,
+                ))
+                .await
+        })
     }
 
     fn execute(
@@ -261,20 +272,29 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        self.transaction.body.retry_policy.clone()
+    }
+
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo {
-            total: self.total_chunks,
-            current: self.current_chunk,
-            initial_transaction_id: self.initial_transaction_id,
-            node_account_id,
-            current_transaction_id: *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-        }))
+        Box::pin(async move {
+            self.transaction
+                .make_request_inner(&ChunkInfo {
+                    total: self.total_chunks,
+                    current: self.current_chunk,
+                    initial_transaction_id: self.initial_transaction_id,
+                    node_account_id,
+                    current_transaction_id: *transaction_id
+                        .ok_or(Error::NoPayerAccountOrTransactionId)?,
+                })
+                .await
+        })
     }
 
     fn execute(
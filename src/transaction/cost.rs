@@ -36,7 +36,9 @@ impl<D: Clone> CostTransaction<D> {
                 transaction_id: transaction.body.transaction_id,
                 operator: transaction.body.operator,
                 is_frozen: transaction.body.is_frozen,
+                is_frozen_offline: transaction.body.is_frozen_offline,
                 regenerate_transaction_id: transaction.body.regenerate_transaction_id,
+                retry_policy: transaction.body.retry_policy,
             },
             // cost transactions have no signers
             signers: Vec::new(),
@@ -21,9 +21,11 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use prost::Message;
 use tonic::transport::Channel;
+use triomphe::Arc;
 
 use super::chunked::ChunkInfo;
 use super::source::SourceChunk;
@@ -43,6 +45,7 @@ use crate::{
     Error,
     Hbar,
     PublicKey,
+    RetryPolicy,
     ToProtobuf,
     Transaction,
     TransactionHash,
@@ -85,10 +88,10 @@ impl<D> Transaction<D>
 where
     D: TransactionData + ToTransactionDataProtobuf,
 {
-    pub(crate) fn make_request_inner(
+    pub(crate) async fn make_request_inner(
         &self,
         chunk_info: &ChunkInfo,
-    ) -> (services::Transaction, TransactionHash) {
+    ) -> crate::Result<(services::Transaction, TransactionHash)> {
         assert!(self.is_frozen());
 
         let transaction_body = self.to_transaction_body_protobuf(chunk_info);
@@ -98,7 +101,7 @@ where
         let mut signatures = Vec::with_capacity(1 + self.signers.len());
 
         if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
+            let operator_signature = operator.sign(&body_bytes).await?;
 
             signatures.push(SignaturePair::from(operator_signature).into_protobuf());
         }
@@ -106,7 +109,7 @@ where
         for signer in &self.signers {
             let public_key = signer.public_key().to_bytes();
             if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let signature = signer.sign(&body_bytes);
+                let signature = signer.sign(&body_bytes).await?;
                 signatures.push(SignaturePair::from(signature).into_protobuf());
             }
         }
@@ -123,7 +126,7 @@ where
         let transaction =
             services::Transaction { signed_transaction_bytes, ..services::Transaction::default() };
 
-        (transaction, transaction_hash)
+        Ok((transaction, transaction_hash))
     }
 }
 
@@ -156,7 +159,7 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
 }
 
 pub trait TransactionExecute:
-    ToTransactionDataProtobuf + TransactionData + ValidateChecksums
+    ToTransactionDataProtobuf + TransactionData + ValidateChecksums + Send + Sync
 {
     fn execute(
         &self,
@@ -197,17 +200,24 @@ where
         self.body.regenerate_transaction_id
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        self.body.retry_policy.clone()
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.is_frozen());
 
-        Ok(self.make_request_inner(&ChunkInfo::single(
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            self.make_request_inner(&ChunkInfo::single(
+                *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                node_account_id,
+            ))
+            .await
+        })
     }
 
     fn execute(
@@ -310,11 +320,14 @@ pub(crate) struct SourceTransaction<'a, D> {
 }
 
 impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
+    pub(crate) async fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+    ) -> crate::Result<Self> {
         // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+        let sources = sources.sign_with(&transaction.signers).await?;
 
-        Self { inner: transaction, sources }
+        Ok(Self { inner: transaction, sources })
     }
 
     pub(crate) async fn execute(
@@ -406,15 +419,22 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         Some(false)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        self.transaction.body.retry_policy.clone()
+    }
+
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         debug_assert_eq!(transaction_id, self.transaction_id().as_ref());
 
         let index = *self.indecies_by_node_id.get(&node_account_id).unwrap();
-        Ok((self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]))
+        Box::pin(std::future::ready(Ok((
+            self.chunk.transactions()[index].clone(),
+            self.chunk.transaction_hashes()[index],
+        ))))
     }
 
     fn execute(
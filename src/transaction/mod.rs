@@ -31,10 +31,17 @@ use hedera_proto::services;
 use prost::Message;
 use time::Duration;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use crate::downcast::DowncastOwned;
 use crate::execute::execute;
-use crate::signer::AnySigner;
+use crate::signer::{
+    sign_now,
+    AnySigner,
+};
 use crate::{
     AccountId,
     Client,
@@ -43,7 +50,9 @@ use crate::{
     Operator,
     PrivateKey,
     PublicKey,
+    RetryPolicy,
     ScheduleCreateTransaction,
+    Signer,
     TransactionHash,
     TransactionId,
     TransactionResponse,
@@ -54,6 +63,7 @@ mod any;
 mod chunked;
 mod cost;
 mod execute;
+mod offline;
 mod protobuf;
 mod source;
 #[cfg(test)]
@@ -72,6 +82,10 @@ pub(crate) use execute::{
     TransactionExecute,
     TransactionExecuteChunked,
 };
+pub use offline::{
+    OfflineFeePolicy,
+    OfflineTransactionBuilder,
+};
 pub(crate) use protobuf::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
@@ -108,7 +122,12 @@ pub(crate) struct TransactionBody<D> {
 
     pub(crate) is_frozen: bool,
 
+    /// Frozen with [`freeze_offline`](Transaction::freeze_offline), so there's no operator to generate chunk transaction IDs.
+    pub(crate) is_frozen_offline: bool,
+
     pub(crate) regenerate_transaction_id: Option<bool>,
+
+    pub(crate) retry_policy: Option<Arc<dyn RetryPolicy>>,
 }
 
 impl<D> Default for Transaction<D>
@@ -126,7 +145,9 @@ where
                 transaction_id: None,
                 operator: None,
                 is_frozen: false,
+                is_frozen_offline: false,
                 regenerate_transaction_id: None,
+                retry_policy: None,
             },
             signers: Vec::new(),
             sources: None,
@@ -174,8 +195,11 @@ impl<D> Transaction<D> {
         self.sources.as_ref()
     }
 
-    fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
-        self.sources().map(|it| it.sign_with(&self.signers))
+    async fn signed_sources(&self) -> crate::Result<Option<Cow<'_, TransactionSources>>> {
+        match self.sources() {
+            Some(it) => it.sign_with(&self.signers).await.map(Some),
+            None => Ok(None),
+        }
     }
 
     /// # Panics
@@ -293,6 +317,20 @@ impl<D> Transaction<D> {
         self
     }
 
+    /// Returns the retry policy this transaction uses instead of the one on the client, if any.
+    #[must_use]
+    pub fn get_retry_policy(&self) -> Option<&dyn RetryPolicy> {
+        self.body.retry_policy.as_deref()
+    }
+
+    /// Sets the [`RetryPolicy`] to use when executing this transaction, instead of the one on the client.
+    ///
+    /// The retry policy isn't part of the transaction itself, so unlike most setters this works on frozen transactions.
+    pub fn retry_policy<P: RetryPolicy + 'static>(&mut self, policy: P) -> &mut Self {
+        self.body.retry_policy = Some(Arc::new(policy).unsize(Coercion!(to dyn RetryPolicy)));
+        self
+    }
+
     /// Sign the transaction.
     pub fn sign(&mut self, private_key: PrivateKey) -> &mut Self {
         self.sign_signer(AnySigner::PrivateKey(private_key))
@@ -307,6 +345,14 @@ impl<D> Transaction<D> {
         self.sign_signer(AnySigner::arbitrary(Box::new(public_key), signer))
     }
 
+    /// Sign the transaction with a [`Signer`].
+    ///
+    /// Signers that don't complete immediately (such as remote signers) can only be used when executing,
+    /// synchronous methods like [`to_bytes`](Self::to_bytes) will return [`Error::Signature`] instead.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.sign_signer(AnySigner::signer(signer))
+    }
+
     pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
         // We're _supposed_ to require frozen here, but really there's no reason I can think of to do that.
 
@@ -375,6 +421,8 @@ impl<D: ChunkedTransactionData> Transaction<D> {
 impl<D: ValidateChecksums> Transaction<D> {
     /// Freeze the transaction so that no further modifications can be made.
     ///
+    /// See [`freeze_offline`](Self::freeze_offline) for providing everything a client would, without one.
+    ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set.
     ///
@@ -408,12 +456,12 @@ impl<D: ValidateChecksums> Transaction<D> {
             }
             #[allow(clippy::missing_panics_doc)]
             None => {
+                let client = client.ok_or(Error::FreezeUnsetNodeAccountIds)?;
                 let nodes = client
-                    .ok_or(Error::FreezeUnsetNodeAccountIds)?
                     .net()
                     .0
                     .load()
-                    .random_node_ids();
+                    .select_node_ids(&*client.node_selector(), client.circuit_breaker().is_some());
                 assert!(!nodes.is_empty(), "BUG: Client didn't give any nodes (all unhealthy)");
 
                 nodes
@@ -449,6 +497,93 @@ impl<D: ValidateChecksums> Transaction<D> {
         Ok(self)
     }
 
+    /// Freeze the transaction without a [`Client`], using what `offline` provides instead.
+    ///
+    /// Unlike [`freeze`](Self::freeze) the result can always be signed and serialized with [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::FreezeOfflineMissing`] listing everything that neither the transaction nor `offline` provides.
+    /// - [`Error::BadEntityId`] if an entity ID's checksum doesn't match `offline`'s ledger ID.
+    pub fn freeze_offline(
+        &mut self,
+        offline: &OfflineTransactionBuilder,
+    ) -> crate::Result<&mut Self> {
+        if self.is_frozen() {
+            return Ok(self);
+        }
+
+        let mut missing = Vec::new();
+
+        if offline.ledger_id.is_none() {
+            missing.push("ledger ID");
+        }
+
+        let node_account_ids = self.body.node_account_ids.clone().or_else(|| {
+            let nodes = offline.node_account_ids.clone();
+            if nodes.is_none() {
+                missing.push("node account IDs");
+            }
+
+            nodes
+        });
+
+        let transaction_id = self.body.transaction_id.or_else(|| {
+            let Some(account_id) = offline.payer_account_id else {
+                missing.push("payer account ID");
+                return None;
+            };
+
+            Some(match offline.valid_start {
+                Some(valid_start) => {
+                    TransactionId { account_id, valid_start, nonce: None, scheduled: false }
+                }
+                None => TransactionId::generate(account_id),
+            })
+        });
+
+        let max_transaction_fee = match (self.body.max_transaction_fee, offline.fee_policy) {
+            (Some(fee), _) | (None, Some(OfflineFeePolicy::Max(fee))) => Some(fee),
+            // the transaction's default gets used when it's serialized.
+            (None, Some(OfflineFeePolicy::TransactionDefault)) => None,
+            (None, Some(OfflineFeePolicy::RequireExplicit)) => {
+                missing.push("max transaction fee");
+                None
+            }
+            (None, None) => {
+                missing.push("fee policy");
+                None
+            }
+        };
+
+        let (ledger_id, node_account_ids, transaction_id) =
+            match (&offline.ledger_id, node_account_ids, transaction_id) {
+                (Some(ledger_id), Some(node_account_ids), Some(transaction_id))
+                    if missing.is_empty() =>
+                {
+                    (ledger_id, node_account_ids, transaction_id)
+                }
+                _ => return Err(Error::FreezeOfflineMissing { missing }),
+            };
+
+        // validate before anything changes, so a rejected transaction doesn't end up frozen.
+        let ledger_id = ledger_id.as_ref_ledger_id();
+        for node_account_id in &node_account_ids {
+            node_account_id.validate_checksums(ledger_id)?;
+        }
+        transaction_id.validate_checksums(ledger_id)?;
+        self.body.data.validate_checksums(ledger_id)?;
+
+        self.body.node_account_ids = Some(node_account_ids);
+        self.body.transaction_id = Some(transaction_id);
+        self.body.max_transaction_fee = max_transaction_fee;
+        // a client would leave this to the default too, but the bytes being signed shouldn't depend on it.
+        self.body.transaction_valid_duration.get_or_insert(DEFAULT_TRANSACTION_VALID_DURATION);
+        self.body.is_frozen = true;
+        self.body.is_frozen_offline = true;
+
+        Ok(self)
+    }
+
     /// Sign the transaction with the `client`'s operator.
     ///
     /// # Errors
@@ -473,9 +608,11 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Errors
     /// - If the transaction needs multiple chunks, or has no explicit transaction ID *and* `self.operator` is not set.
     ///
+    /// - If a signer fails to sign the transaction.
+    ///
     /// # Panics
     /// - If `!self.is_frozen()`
-    fn make_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
+    async fn make_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
         assert!(self.is_frozen());
 
         let operator = || self.body.operator.as_ref().ok_or(Error::NoPayerAccountOrTransactionId);
@@ -497,6 +634,17 @@ impl<D: TransactionExecute> Transaction<D> {
         for chunk in 0..used_chunks {
             let current_transaction_id = match chunk {
                 0 => initial_transaction_id,
+                // there's no operator to generate the following chunks' IDs with,
+                // so they're valid a nanosecond after each other instead.
+                // they all share (give or take a few nanoseconds) the first chunk's valid duration,
+                // so they have to be submitted within it, just like chunks with generated IDs.
+                _ if self.body.is_frozen_offline => TransactionId {
+                    valid_start: initial_transaction_id.valid_start
+                        + Duration::nanoseconds(
+                            i64::try_from(chunk).expect("chunk count should fit in an i64"),
+                        ),
+                    ..initial_transaction_id
+                },
                 _ => operator()?.generate_transaction_id(),
             };
 
@@ -509,41 +657,47 @@ impl<D: TransactionExecute> Transaction<D> {
                     node_account_id,
                 };
 
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                transaction_list.push(self.make_request_inner(&chunk_info).await?.0);
             }
         }
 
         Ok(transaction_list)
     }
 
-    pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
+    pub(crate) async fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
         assert!(self.is_frozen());
 
-        if let Some(sources) = self.signed_sources() {
+        if let Some(sources) = self.signed_sources().await? {
             return Ok(sources);
         }
 
-        return Ok(Cow::Owned(TransactionSources::new(self.make_transaction_list()?).unwrap()));
+        return Ok(Cow::Owned(
+            TransactionSources::new(self.make_transaction_list().await?).unwrap(),
+        ));
     }
 
     /// Convert `self` to protobuf encoded bytes.
     ///
     /// # Errors
     /// - If `freeze_with` wasn't called with an operator.
+    /// - [`Error::Signature`] if a signer fails, or doesn't complete immediately.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
     pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen(), "Transaction must be frozen to call `to_bytes`");
 
-        let transaction_list = self
-            .signed_sources()
-            .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
+        let transaction_list = sign_now(async {
+            match self.signed_sources().await? {
+                Some(it) => Ok(it.transactions().to_vec()),
+                None => self.make_transaction_list().await,
+            }
+        })?;
 
         Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
     }
 
-    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
+    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen());
 
         // note: the following pair of cheecks are for more detailed panic messages
@@ -563,34 +717,58 @@ impl<D: TransactionExecute> Transaction<D> {
             );
         }
 
-        let sources = self.make_sources().unwrap();
+        let (sources, ret) = sign_now(async {
+            let sources = self.make_sources().await?;
 
-        // this is the only check that is for correctness rather than debugability.
-        assert!(sources.transactions().len() == 1);
+            // this is the only check that is for correctness rather than debugability.
+            assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+            let sources = sources.sign_with(std::slice::from_ref(signer)).await?;
 
-        // hack: I don't care about perf here.
-        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
+            // hack: I don't care about perf here.
+            let ret = signer.sign(&sources.signed_transactions()[0].body_bytes).await?;
 
-        // if we have a `Cow::Borrowed` that'd mean there was no modification
-        if let Cow::Owned(sources) = sources {
+            // if we have a `Cow::Borrowed` that'd mean there was no modification
+            let sources = match sources {
+                Cow::Owned(it) => Some(it),
+                Cow::Borrowed(_) => None,
+            };
+
+            crate::Result::Ok((sources, ret.1))
+        })?;
+
+        if let Some(sources) = sources {
             self.sources = Some(sources);
         }
 
-        ret.1
+        Ok(ret)
     }
 
-    // todo: should this return `Result<&mut Self>`?
     /// Adds a signature directly to `self`.
     ///
     /// Only use this as a last resort.
     ///
     /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Panics
+    /// - If one of the transaction's signers fails, or doesn't complete immediately,
+    ///   use [`try_add_signature`](Self::try_add_signature) to get an error instead.
     pub fn add_signature(&mut self, pk: PublicKey, signature: Vec<u8>) -> &mut Self {
-        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()));
+        self.try_add_signature(pk, signature).expect("failed to add a signature")
+    }
 
-        self
+    /// Adds a signature directly to `self`, like [`add_signature`](Self::add_signature).
+    ///
+    /// # Errors
+    /// - [`Error::Signature`] if one of the transaction's signers fails, or doesn't complete immediately.
+    pub fn try_add_signature(
+        &mut self,
+        pk: PublicKey,
+        signature: Vec<u8>,
+    ) -> crate::Result<&mut Self> {
+        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()))?;
+
+        Ok(self)
     }
 
     /// # Panics
@@ -620,6 +798,7 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Errors
     /// - [`Error::NoPayerAccountOrTransactionId`]
     ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails, or doesn't complete immediately.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -630,7 +809,7 @@ impl<D: TransactionExecute> Transaction<D> {
             "Transaction must be frozen before calling `get_transaction_hash`"
         );
 
-        let sources = self.make_sources()?;
+        let sources = sign_now(self.make_sources())?;
 
         let sources = match sources {
             Cow::Borrowed(it) => it,
@@ -647,6 +826,7 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Errors
     /// - [`Error::NoPayerAccountOrTransactionId`]
     ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails, or doesn't complete immediately.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -659,7 +839,7 @@ impl<D: TransactionExecute> Transaction<D> {
             "Transaction must be frozen before calling `get_transaction_hash`"
         );
 
-        let sources = self.make_sources()?;
+        let sources = sign_now(self.make_sources())?;
 
         let chunk = sources.chunks().next().unwrap();
 
@@ -671,6 +851,58 @@ impl<D: TransactionExecute> Transaction<D> {
 
         Ok(iter.collect())
     }
+
+    /// Returns every signature on this transaction,
+    /// by node account ID, then transaction ID (chunked transactions have one per chunk), then public key.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails, or doesn't complete immediately.
+    /// - [`Error::FromProtobuf`] if the transaction was deserialized with a signature
+    ///     that isn't an ed25519 or ECDSA(secp256k1) signature with a full public key.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn signatures(
+        &self,
+    ) -> crate::Result<HashMap<AccountId, HashMap<TransactionId, HashMap<PublicKey, Vec<u8>>>>>
+    {
+        assert!(self.is_frozen(), "Transaction must be frozen before calling `signatures`");
+
+        sign_now(self.make_sources())?.signatures()
+    }
+
+    /// Adds the signatures on `other`, a copy of this transaction signed by someone else, to this transaction.
+    ///
+    /// This is for when several parties each sign their own copy of a transaction,
+    /// such as the bytes of a frozen transaction passed around to multiple offline signers.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionMergeMismatch`] if `other`'s transaction bodies aren't byte-for-byte identical to `self`'s.
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if either transaction wasn't frozen with an operator and has no transaction ID.
+    /// - [`Error::Signature`] if a signer fails, or doesn't complete immediately.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()` or `!other.is_frozen()`.
+    pub fn merge(&mut self, other: &Self) -> crate::Result<&mut Self> {
+        assert!(
+            self.is_frozen() && other.is_frozen(),
+            "Transactions must be frozen before calling `merge`"
+        );
+
+        let sources = sign_now(async {
+            let sources = self.make_sources().await?;
+            let other = other.make_sources().await?;
+
+            sources.merge(&other)
+        })?;
+
+        self.sources = Some(sources);
+
+        Ok(self)
+    }
 }
 
 impl<D> Transaction<D>
@@ -732,6 +964,7 @@ where
 
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute(client, timeout)
                 .await;
         }
@@ -852,6 +1085,7 @@ where
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute_all(client, timeout_per_chunk)
                 .await;
         }
@@ -891,16 +1125,7 @@ impl AnyTransaction {
     /// - [`Error::FromProtobuf`] if a valid transaction cannot be parsed from the bytes.
     #[allow(deprecated)]
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
-        let list =
-            hedera_proto::sdk::TransactionList::decode(bytes).map_err(Error::from_protobuf)?;
-
-        let list = if list.transaction_list.is_empty() {
-            Vec::from([services::Transaction::decode(bytes).map_err(Error::from_protobuf)?])
-        } else {
-            list.transaction_list
-        };
-
-        let sources = TransactionSources::new(list)?;
+        let sources = TransactionSources::from_bytes(bytes)?;
 
         let transaction_bodies: Result<Vec<_>, _> = sources
             .signed_transactions()
@@ -1055,6 +1280,7 @@ where
             operator,
             is_frozen,
             regenerate_transaction_id,
+            retry_policy,
         } = body;
 
         // not a `map().map_err()` because ownership.
@@ -1070,6 +1296,7 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    retry_policy,
                 },
                 signers,
                 sources,
@@ -1086,6 +1313,7 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    retry_policy,
                 },
                 signers,
                 sources,
@@ -1139,7 +1367,8 @@ pub(crate) mod test_helpers {
     ) -> services::TransactionBody {
         // if you're thinking "ghee, that sure is a silly way to get a transaction body" you aren't wrong.
         services::TransactionBody::decode(
-            &*tx.make_sources().unwrap().signed_transactions()[0].body_bytes,
+            &*crate::signer::sign_now(tx.make_sources()).unwrap().signed_transactions()[0]
+                .body_bytes,
         )
         .unwrap()
     }
@@ -1148,7 +1377,7 @@ pub(crate) mod test_helpers {
     pub(crate) fn transaction_bodies<D: TransactionExecute>(
         tx: Transaction<D>,
     ) -> Vec<services::TransactionBody> {
-        tx.make_sources()
+        crate::signer::sign_now(tx.make_sources())
             .unwrap()
             .signed_transactions()
             .iter()
@@ -0,0 +1,164 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::OffsetDateTime;
+
+use crate::{
+    AccountId,
+    Hbar,
+    LedgerId,
+};
+
+/// How [`Transaction::freeze_offline`](crate::Transaction::freeze_offline) picks the max transaction fee
+/// for transactions that don't set their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OfflineFeePolicy {
+    /// Use the transaction type's [`default_max_transaction_fee`](crate::Transaction::default_max_transaction_fee).
+    TransactionDefault,
+
+    /// Use the given fee, like [`Client::set_default_max_transaction_fee`](crate::Client::set_default_max_transaction_fee).
+    Max(Hbar),
+
+    /// Don't pick one, every transaction has to set its own.
+    RequireExplicit,
+}
+
+/// Everything a [`Client`](crate::Client) would otherwise provide when freezing a transaction,
+/// so that transactions can be frozen, signed and serialized on a machine without network access.
+///
+/// Anything set on the transaction itself takes priority, exactly like it does with a client.
+///
+/// # Examples
+/// ```
+/// # fn main() -> hedera::Result<()> {
+/// use hedera::{AccountId, Hbar, LedgerId, OfflineFeePolicy, OfflineTransactionBuilder, PrivateKey, TransferTransaction};
+///
+/// let mut offline = OfflineTransactionBuilder::new();
+/// offline
+///     .ledger_id(LedgerId::mainnet())
+///     .node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)])
+///     .payer_account_id(AccountId::new(0, 0, 1001))
+///     .fee_policy(OfflineFeePolicy::Max(Hbar::new(1)));
+///
+/// let bytes = TransferTransaction::new()
+///     .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(-10))
+///     .hbar_transfer(AccountId::new(0, 0, 1002), Hbar::new(10))
+///     .freeze_offline(&offline)?
+///     .sign(PrivateKey::generate_ed25519())
+///     .to_bytes()?;
+/// # let _ = bytes;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct OfflineTransactionBuilder {
+    pub(super) ledger_id: Option<LedgerId>,
+    pub(super) node_account_ids: Option<Vec<AccountId>>,
+    pub(super) payer_account_id: Option<AccountId>,
+    pub(super) valid_start: Option<OffsetDateTime>,
+    pub(super) fee_policy: Option<OfflineFeePolicy>,
+}
+
+impl OfflineTransactionBuilder {
+    /// Create a new, empty, `OfflineTransactionBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ledger that transactions are frozen for.
+    #[must_use]
+    pub fn get_ledger_id(&self) -> Option<&LedgerId> {
+        self.ledger_id.as_ref()
+    }
+
+    /// Sets the ledger that transactions are frozen for, entity ID checksums are validated against it.
+    ///
+    /// This is always required.
+    pub fn ledger_id(&mut self, ledger_id: LedgerId) -> &mut Self {
+        self.ledger_id = Some(ledger_id);
+        self
+    }
+
+    /// Returns the account IDs of the nodes that transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes that transactions may be submitted to.
+    ///
+    /// Required for transactions without their own [`node_account_ids`](crate::Transaction::node_account_ids).
+    pub fn node_account_ids(&mut self, ids: impl IntoIterator<Item = AccountId>) -> &mut Self {
+        let nodes: Vec<_> = ids.into_iter().collect();
+
+        if nodes.is_empty() {
+            log::warn!("Nodes list is empty, ignoring setter");
+        } else {
+            self.node_account_ids = Some(nodes);
+        }
+
+        self
+    }
+
+    /// Returns the account that pays for transactions.
+    #[must_use]
+    pub fn get_payer_account_id(&self) -> Option<AccountId> {
+        self.payer_account_id
+    }
+
+    /// Sets the account that pays for transactions, their transaction IDs are generated for it.
+    ///
+    /// Required for transactions without their own [`transaction_id`](crate::Transaction::transaction_id).
+    pub fn payer_account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.payer_account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the time generated transaction IDs are valid from.
+    #[must_use]
+    pub fn get_valid_start(&self) -> Option<OffsetDateTime> {
+        self.valid_start
+    }
+
+    /// Sets the time generated transaction IDs are valid from.
+    ///
+    /// Defaults to shortly before the transaction is frozen,
+    /// set this when the transaction is going to be submitted later than that.
+    pub fn valid_start(&mut self, valid_start: OffsetDateTime) -> &mut Self {
+        self.valid_start = Some(valid_start);
+        self
+    }
+
+    /// Returns how the max transaction fee is picked.
+    #[must_use]
+    pub fn get_fee_policy(&self) -> Option<OfflineFeePolicy> {
+        self.fee_policy
+    }
+
+    /// Sets how the max transaction fee is picked.
+    ///
+    /// Required for transactions without their own [`max_transaction_fee`](crate::Transaction::max_transaction_fee).
+    pub fn fee_policy(&mut self, policy: OfflineFeePolicy) -> &mut Self {
+        self.fee_policy = Some(policy);
+        self
+    }
+}
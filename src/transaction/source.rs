@@ -19,6 +19,7 @@
  */
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::ops::Range;
 
 use hedera_proto::services;
@@ -30,6 +31,7 @@ use crate::signer::AnySigner;
 use crate::{
     AccountId,
     Error,
+    PublicKey,
     TransactionHash,
     TransactionId,
 };
@@ -87,6 +89,20 @@ pub struct TransactionSources {
 }
 
 impl TransactionSources {
+    /// Parse a `TransactionList`, or a single `Transaction`, as produced by [`Transaction::to_bytes`](crate::Transaction::to_bytes).
+    pub(crate) fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let list =
+            hedera_proto::sdk::TransactionList::decode(bytes).map_err(Error::from_protobuf)?;
+
+        let list = if list.transaction_list.is_empty() {
+            Vec::from([services::Transaction::decode(bytes).map_err(Error::from_protobuf)?])
+        } else {
+            list.transaction_list
+        };
+
+        Self::new(list)
+    }
+
     pub(crate) fn new(transactions: Vec<services::Transaction>) -> crate::Result<Self> {
         if transactions.is_empty() {
             return Err(Error::from_protobuf("`TransactionList` had no transactions"));
@@ -227,9 +243,9 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
+    pub(crate) async fn sign_with(&self, signers: &[AnySigner]) -> crate::Result<Cow<'_, Self>> {
         if signers.is_empty() {
-            return Cow::Borrowed(self);
+            return Ok(Cow::Borrowed(self));
         }
 
         let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
@@ -250,13 +266,14 @@ impl TransactionSources {
             for tx in signed_transactions.to_mut().iter_mut() {
                 let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
                 // todo: reuse `pk_bytes` instead of re-serializing them.
-                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes));
+                let sig_pair =
+                    super::execute::SignaturePair::from(signer.sign(&tx.body_bytes).await?);
 
                 sig_map.sig_pair.push(sig_pair.into_protobuf());
             }
         }
 
-        match signed_transactions {
+        let sources = match signed_transactions {
             // if it's still borrowed then no signatures have been added (all signers are duplicates).
             Cow::Borrowed(_) => Cow::Borrowed(self),
             Cow::Owned(signed_transactions) => Cow::Owned(Self {
@@ -267,7 +284,93 @@ impl TransactionSources {
                 node_ids: self.node_ids.clone(),
                 transaction_hashes: self.transaction_hashes.clone(),
             }),
+        };
+
+        Ok(sources)
+    }
+
+    /// Returns a copy of `self` with the signatures from `other` that `self` doesn't have yet.
+    ///
+    /// # Errors
+    /// - [`Error::TransactionMergeMismatch`] if the transaction bodies of `self` and `other` aren't identical.
+    pub(crate) fn merge(&self, other: &Self) -> crate::Result<Self> {
+        if self.signed_transactions.len() != other.signed_transactions.len()
+            || self
+                .signed_transactions
+                .iter()
+                .zip(other.signed_transactions.iter())
+                .any(|(lhs, rhs)| lhs.body_bytes != rhs.body_bytes)
+        {
+            return Err(Error::TransactionMergeMismatch);
+        }
+
+        let mut signed_transactions = self.signed_transactions.clone();
+
+        for (tx, other) in signed_transactions.iter_mut().zip(other.signed_transactions.iter()) {
+            let Some(other_sig_map) = &other.sig_map else {
+                continue;
+            };
+
+            let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
+
+            for pair in &other_sig_map.sig_pair {
+                if !sig_map.sig_pair.iter().any(|it| it.pub_key_prefix == pair.pub_key_prefix) {
+                    sig_map.sig_pair.push(pair.clone());
+                }
+            }
         }
+
+        Ok(Self {
+            signed_transactions,
+            transactions: OnceCell::new(),
+            chunks: self.chunks.clone(),
+            transaction_ids: self.transaction_ids.clone(),
+            node_ids: self.node_ids.clone(),
+            transaction_hashes: self.transaction_hashes.clone(),
+        })
+    }
+
+    /// Returns every signature, by node account ID, then transaction ID (one per chunk), then public key.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if a signature isn't an ed25519 or ECDSA(secp256k1) signature with a full public key.
+    pub(crate) fn signatures(
+        &self,
+    ) -> crate::Result<HashMap<AccountId, HashMap<TransactionId, HashMap<PublicKey, Vec<u8>>>>>
+    {
+        use services::signature_pair::Signature;
+
+        let mut signatures: HashMap<_, HashMap<_, _>> = HashMap::new();
+
+        for chunk in self.chunks() {
+            for (node_id, tx) in chunk.node_ids().iter().zip(chunk.signed_transactions()) {
+                let pairs = tx.sig_map.as_ref().map_or(&[][..], |it| it.sig_pair.as_slice());
+
+                let chunk_signatures = pairs
+                    .iter()
+                    .map(|pair| match &pair.signature {
+                        Some(Signature::Ed25519(signature)) => Ok((
+                            PublicKey::from_bytes_ed25519(&pair.pub_key_prefix)?,
+                            signature.clone(),
+                        )),
+                        Some(Signature::EcdsaSecp256k1(signature)) => Ok((
+                            PublicKey::from_bytes_ecdsa(&pair.pub_key_prefix)?,
+                            signature.clone(),
+                        )),
+                        _ => Err(Error::from_protobuf(
+                            "unsupported signature kind in `SignaturePair`",
+                        )),
+                    })
+                    .collect::<crate::Result<HashMap<_, _>>>()?;
+
+                signatures
+                    .entry(*node_id)
+                    .or_default()
+                    .insert(chunk.transaction_id(), chunk_signatures);
+            }
+        }
+
+        Ok(signatures)
     }
 
     pub(crate) fn transactions(&self) -> &[services::Transaction] {
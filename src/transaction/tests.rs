@@ -1,15 +1,24 @@
 use std::collections::HashSet;
+use std::str::FromStr;
 
 use assert_matches::assert_matches;
+use futures_core::future::BoxFuture;
 use hex_literal::hex;
 use time::OffsetDateTime;
 
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountId,
     AnyTransaction,
     Client,
+    Error,
     Hbar,
+    LedgerId,
+    OfflineFeePolicy,
+    OfflineTransactionBuilder,
     PrivateKey,
+    PublicKey,
+    Signer,
     TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
@@ -119,3 +128,216 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn freeze_offline_missing() {
+    let mut tx = TransferTransaction::new();
+
+    let err = tx.freeze_offline(&OfflineTransactionBuilder::new()).unwrap_err();
+
+    assert_matches!(err, Error::FreezeOfflineMissing { missing } => {
+        assert_eq!(
+            missing,
+            ["ledger ID", "node account IDs", "payer account ID", "fee policy"]
+        );
+    });
+
+    let mut offline = OfflineTransactionBuilder::new();
+    offline.ledger_id(LedgerId::testnet()).fee_policy(OfflineFeePolicy::RequireExplicit);
+
+    let err = tx.node_account_ids([3.into()]).freeze_offline(&offline).unwrap_err();
+
+    assert_matches!(err, Error::FreezeOfflineMissing { missing } => {
+        assert_eq!(missing, ["payer account ID", "max transaction fee"]);
+    });
+}
+
+#[test]
+fn freeze_offline_bad_checksum() {
+    let mut offline = OfflineTransactionBuilder::new();
+    offline
+        .ledger_id(LedgerId::testnet())
+        .payer_account_id(101.into())
+        .fee_policy(OfflineFeePolicy::TransactionDefault);
+
+    // a mainnet checksum.
+    let mut tx = TransferTransaction::new();
+    tx.node_account_ids([AccountId::from_str("0.0.3-tzfmz").unwrap()]);
+
+    assert_matches!(tx.freeze_offline(&offline), Err(Error::BadEntityId { .. }));
+    assert!(!tx.is_frozen());
+
+    // trying again mustn't skip the validation.
+    assert_matches!(tx.freeze_offline(&offline), Err(Error::BadEntityId { .. }));
+    assert!(!tx.is_frozen());
+}
+
+#[test]
+fn freeze_offline_chunked_to_from_bytes() -> crate::Result<()> {
+    let valid_start = OffsetDateTime::now_utc();
+
+    let mut offline = OfflineTransactionBuilder::new();
+    offline
+        .ledger_id(LedgerId::testnet())
+        .node_account_ids([6.into(), 7.into()])
+        .payer_account_id(101.into())
+        .valid_start(valid_start)
+        .fee_policy(OfflineFeePolicy::Max(Hbar::new(3)));
+
+    let bytes = TopicMessageSubmitTransaction::new()
+        .topic_id(314)
+        .message(b"Hello, world!".to_vec())
+        .chunk_size(8)
+        .max_chunks(2)
+        .freeze_offline(&offline)?
+        .sign(PrivateKey::generate_ed25519())
+        .to_bytes()?;
+
+    let tx2 = AnyTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(
+        tx2.get_transaction_id(),
+        Some(TransactionId { account_id: 101.into(), valid_start, nonce: None, scheduled: false })
+    );
+    assert_eq!(tx2.get_max_transaction_fee(), Some(Hbar::new(3)));
+    assert_eq!(tx2.get_transaction_valid_duration(), Some(time::Duration::seconds(120)));
+
+    let nodes: Option<HashSet<_>> =
+        tx2.get_node_account_ids().map(|it| it.iter().copied().collect());
+    assert_eq!(nodes, Some(HashSet::from([6.into(), 7.into()])));
+
+    Ok(())
+}
+
+#[test]
+fn freeze_chunked_without_operator() -> crate::Result<()> {
+    // only `freeze_offline` makes up IDs for the following chunks, `freeze` needs an operator for them.
+    let res = TopicMessageSubmitTransaction::new()
+        .topic_id(314)
+        .message(b"Hello, world!".to_vec())
+        .chunk_size(8)
+        .max_chunks(2)
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .to_bytes();
+
+    assert_matches!(res, Err(Error::NoPayerAccountOrTransactionId));
+
+    Ok(())
+}
+
+#[test]
+fn merge_signatures() -> crate::Result<()> {
+    let key1 = PrivateKey::generate_ed25519();
+    let key2 = PrivateKey::generate_ecdsa();
+
+    let bytes = TransferTransaction::new()
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?
+        .to_bytes()?;
+
+    let mut tx1 = AnyTransaction::from_bytes(&bytes)?;
+    tx1.sign(key1.clone());
+
+    let mut tx2 = AnyTransaction::from_bytes(&bytes)?;
+    tx2.sign(key2.clone());
+
+    let tx1 = AnyTransaction::from_bytes(&tx1.to_bytes()?)?;
+    let tx2 = AnyTransaction::from_bytes(&tx2.to_bytes()?)?;
+
+    let mut merged = tx1.clone();
+    merged.merge(&tx2)?;
+
+    let signatures = merged.signatures()?;
+
+    assert_eq!(signatures.len(), 2);
+
+    for by_transaction_id in signatures.values() {
+        assert_eq!(by_transaction_id.len(), 1);
+
+        let by_key = by_transaction_id.values().next().unwrap();
+        assert_eq!(by_key.len(), 2);
+        assert!(by_key.contains_key(&key1.public_key()));
+        assert!(by_key.contains_key(&key2.public_key()));
+    }
+
+    // merging is idempotent.
+    merged.merge(&tx1)?;
+    assert_eq!(merged.signatures()?, signatures);
+
+    Ok(())
+}
+
+#[test]
+fn merge_mismatched() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()]);
+
+    let mut tx2 = tx.clone();
+    tx2.transaction_memo("different");
+
+    tx.freeze()?;
+    tx2.freeze()?;
+
+    assert_matches!(tx.merge(&tx2), Err(Error::TransactionMergeMismatch));
+
+    Ok(())
+}
+
+#[test]
+fn add_signature_failing_signer() -> crate::Result<()> {
+    struct FailingSigner(PublicKey);
+
+    impl Signer for FailingSigner {
+        fn public_key(&self) -> PublicKey {
+            self.0
+        }
+
+        fn sign<'a>(&'a self, _message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>> {
+            Box::pin(std::future::ready(Err(Error::signature("the signer is offline"))))
+        }
+    }
+
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .sign_with_signer(FailingSigner(PrivateKey::generate_ed25519().public_key()));
+
+    let signature = key.sign(b"not the transaction");
+
+    assert_matches!(tx.try_add_signature(key.public_key(), signature), Err(Error::Signature(_)));
+
+    Ok(())
+}
@@ -29,8 +29,11 @@ use crate::query::{
     QueryExecute,
     ToQueryProtobuf,
 };
+#[cfg(feature = "serde")]
+use crate::ContractAbi;
 use crate::{
     BoxGrpcFuture,
+    ContractFunctionResult,
     Error,
     FromProtobuf,
     Query,
@@ -51,6 +54,8 @@ pub struct TransactionRecordQueryData {
     include_children: bool,
     include_duplicates: bool,
     validate_status: bool,
+    #[cfg(feature = "serde")]
+    contract_abi: Option<ContractAbi>,
 }
 
 impl From<TransactionRecordQueryData> for AnyQueryData {
@@ -110,6 +115,22 @@ impl TransactionRecordQuery {
         self.data.validate_status = validate;
         self
     }
+
+    /// Returns the ABI used to decode the custom errors of a reverted contract call.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn get_contract_abi(&self) -> Option<&ContractAbi> {
+        self.data.contract_abi.as_ref()
+    }
+
+    /// Sets the ABI used to decode the custom errors of a reverted contract call.
+    ///
+    /// Only used when the status is validated, see [`validate_status`](Self::validate_status).
+    #[cfg(feature = "serde")]
+    pub fn contract_abi(&mut self, abi: ContractAbi) -> &mut Self {
+        self.data.contract_abi = Some(abi);
+        self
+    }
 }
 
 impl ToQueryProtobuf for TransactionRecordQueryData {
@@ -136,6 +157,11 @@ impl QueryExecute for TransactionRecordQueryData {
         self.transaction_id
     }
 
+    #[cfg(feature = "serde")]
+    fn contract_abi(&self) -> Option<&ContractAbi> {
+        self.contract_abi.as_ref()
+    }
+
     fn execute(
         &self,
         channel: Channel,
@@ -152,6 +178,28 @@ impl QueryExecute for TransactionRecordQueryData {
         let record = TransactionRecord::from_protobuf(response)?;
 
         if self.validate_status && record.receipt.status != Status::Success {
+            let revert = match record.receipt.status {
+                Status::ContractRevertExecuted => record
+                    .contract_function_result
+                    .as_ref()
+                    .and_then(ContractFunctionResult::revert),
+                _ => None,
+            };
+
+            #[cfg(feature = "serde")]
+            let revert = match (revert, &self.contract_abi) {
+                (Some(revert), Some(abi)) => Some(revert.with_abi(abi)),
+                (revert, _) => revert,
+            };
+
+            if let Some(revert) = revert {
+                return Err(Error::ContractRevert {
+                    status: record.receipt.status,
+                    transaction_id: self.transaction_id.map(Box::new),
+                    revert: Box::new(revert),
+                });
+            }
+
             return Err(Error::ReceiptStatus {
                 transaction_id: self.transaction_id.map(Box::new),
                 status: record.receipt.status,
@@ -90,6 +90,8 @@ impl TransactionResponse {
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
+    ///   Receipts don't contain the result of a contract call, so a reverted call is only reported by its status,
+    ///   use [`get_record`](Self::get_record) to get the decoded revert reason.
     ///
     /// fixme: is that it? Surely there are more situations.
     pub async fn get_receipt(&self, client: &Client) -> crate::Result<TransactionReceipt> {
@@ -102,6 +104,8 @@ impl TransactionResponse {
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
     ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt.
+    ///   Receipts don't contain the result of a contract call, so a reverted call is only reported by its status,
+    ///   use [`get_record`](Self::get_record) to get the decoded revert reason.
     pub async fn get_receipt_with_timeout(
         &self,
         client: &Client,
@@ -115,7 +119,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record,
+    ///   or [`Error::ContractRevert`](crate::Error::ContractRevert) if it's a reverted contract call.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute(client).await
     }
@@ -125,7 +130,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record,
+    ///   or [`Error::ContractRevert`](crate::Error::ContractRevert) if it's a reverted contract call.
     pub async fn get_record_with_timeout(
         &self,
         client: &Client,